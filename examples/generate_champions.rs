@@ -0,0 +1,78 @@
+//! Regenerates `src/constants/champion/generated.rs` from ddragon's `champion.json`.
+//!
+//! ```sh
+//! # latest ddragon version
+//! cargo run --example generate_champions
+//! # local file, e.g. from an unpacked dragontail archive
+//! cargo run --example generate_champions -- path/to/champion.json
+//! ```
+use narwhalol::ddragon::DDragonClient;
+use narwhalol::{AllChampions, LanguageCode};
+
+use std::fmt::Write;
+
+const OUTPUT: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/src/constants/champion/generated.rs"
+);
+
+fn main() {
+    let champions: AllChampions = match std::env::args().nth(1) {
+        Some(path) => serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap(),
+        None => smol::run(async {
+            let mut ddragon = DDragonClient::new(LanguageCode::UNITED_STATES)
+                .await
+                .unwrap();
+            ddragon.get_champions().await.unwrap()
+        }),
+    };
+
+    let mut entries: Vec<(i64, &str, &str)> = champions
+        .data
+        .values()
+        .map(|c| (c.key.parse().unwrap(), c.id.as_str(), c.name.as_str()))
+        .collect();
+    entries.sort();
+
+    let mut source = String::new();
+    writeln!(
+        source,
+        "// Generated by `cargo run --example generate_champions` from ddragon {}, do not edit.",
+        champions.version
+    )
+    .unwrap();
+    source.push_str("use super::{Champion, KnownChampion};\n\nimpl Champion {\n");
+    for (key, id, name) in &entries {
+        writeln!(source, "    /// {}", name).unwrap();
+        writeln!(
+            source,
+            "    pub const {}: Champion = Champion::Known(KnownChampion({}));",
+            const_name(id),
+            key
+        )
+        .unwrap();
+    }
+    source.push_str("}\n\n/// `(key, ddragon id, name)` of every champion, sorted by key.\n");
+    source.push_str("pub(super) const CHAMPIONS: &[(i64, &str, &str)] = &[\n");
+    for (key, id, name) in &entries {
+        writeln!(source, "    ({}, {:?}, {:?}),", key, id, name).unwrap();
+    }
+    source.push_str("];\n");
+
+    std::fs::write(OUTPUT, source).unwrap();
+    println!("Wrote {} champions to {}", entries.len(), OUTPUT);
+}
+
+/// `LeeSin` becomes `LEE_SIN`, `JarvanIV` becomes `JARVAN_IV`.
+fn const_name(id: &str) -> String {
+    let mut name = String::with_capacity(id.len() + 4);
+    let mut prev_lowercase = false;
+    for c in id.chars() {
+        if c.is_uppercase() && prev_lowercase {
+            name.push('_');
+        }
+        prev_lowercase = c.is_lowercase();
+        name.extend(c.to_uppercase());
+    }
+    name
+}
@@ -4,12 +4,14 @@
 //! This module contains all the things needed to talk with Riot API.
 //! The most important type here is
 //! [`LeagueClient`], as it is the main way of getting the data from API. See [`LeagueClient`] for more information.
-use crate::constants::{LanguageCode, RankedQueue, RankedTier, Region};
+use crate::constants::{Champion, LanguageCode, RankedQueue, RankedTier, Region};
 use crate::ddragon::DDragonClient;
+use crate::de;
 use crate::dto::api::{ChampionInfo, ChampionMastery, LeagueInfo, Summoner};
+use crate::dto::ids::SummonerId;
 use crate::error::*;
 use crate::types::{Cache, Client};
-use crate::utils::{construct_hyper_client, CachedClient};
+use crate::utils::{construct_hyper_client, fetch, CachedClient};
 use futures::prelude::*;
 
 use hyper::{Body, HeaderMap, Request, Uri};
@@ -50,6 +52,7 @@ pub struct LeagueClient {
     base_url: String,
     ddragon: Option<DDragonClient>,
     api_key: String,
+    lenient: bool,
 }
 
 impl LeagueClient {
@@ -70,6 +73,7 @@ impl LeagueClient {
             cache,
             client,
             api_key,
+            lenient: false,
         })
     }
 
@@ -78,13 +82,26 @@ impl LeagueClient {
         let ddragon =
             DDragonClient::new_for_lapi(self.client.clone(), self.cache.clone(), language)
                 .await
-                .unwrap();
+                .unwrap()
+                .lenient(self.lenient);
         LeagueClient {
             ddragon: Some(ddragon),
             ..self
         }
     }
 
+    /// Tolerates changes in Riot's response format, including the embedded ddragon client.
+    ///
+    /// Fields that fail to deserialize are dropped with a warning instead of failing the request,
+    /// so optional and defaulted fields fall back to their defaults.
+    pub fn lenient(self, lenient: bool) -> Self {
+        LeagueClient {
+            ddragon: self.ddragon.map(|ddragon| ddragon.lenient(lenient)),
+            lenient,
+            ..self
+        }
+    }
+
     /// Gets mutable (because of cache) reference to ddragon client embedded in lapi client.
     ///
     /// # Panics
@@ -135,7 +152,7 @@ impl LeagueClient {
 
     pub async fn get_champion_masteries(
         &mut self,
-        summoner_id: &str,
+        summoner_id: &SummonerId,
     ) -> Result<Vec<ChampionMastery>, ClientError> {
         trace!("Getting champion masteries for id: {}", &summoner_id);
         let url: Uri = format!(
@@ -147,21 +164,29 @@ impl LeagueClient {
         self.cached_resp(url).await
     }
 
-    pub async fn get_champion_mastery_by_id(
+    /// Gets mastery of a single champion, given as [`Champion`] or numeric key.
+    ///
+    /// [`Champion`]: ../constants/champion/enum.Champion.html
+    pub async fn get_champion_mastery_by_id<C: Into<Champion>>(
         &mut self,
-        summoner_id: &str,
-        champion_id: u64,
+        summoner_id: &SummonerId,
+        champion: C,
     ) -> Result<ChampionMastery, ClientError> {
         let url: Uri = format!(
             "{}/champion-mastery/v4/champion-masteries/by-summoner/{}/by-champion/{}",
-            self.base_url, summoner_id, champion_id
+            self.base_url,
+            summoner_id,
+            champion.into().key()
         )
         .parse()
         .unwrap();
         self.cached_resp(url).await
     }
 
-    pub async fn get_total_mastery_score(&mut self, summoner_id: &str) -> Result<i32, ClientError> {
+    pub async fn get_total_mastery_score(
+        &mut self,
+        summoner_id: &SummonerId,
+    ) -> Result<i32, ClientError> {
         let url: Uri = format!(
             "{}/champion-mastery/v4/scores/by-summoner/{}",
             self.base_url, summoner_id
@@ -195,11 +220,6 @@ impl LeagueClient {
 
         self.cached_resp(url).await
     }
-
-    #[cfg(test)]
-    pub(crate) fn get_status(&self, status: u16) -> Result<(), ClientError> {
-        ClientError::check_status(self.region.clone(), status)
-    }
 }
 
 #[async_trait]
@@ -208,32 +228,26 @@ impl CachedClient for LeagueClient {
         &self,
         url: Uri,
     ) -> Result<T, ClientError> {
-        let maybe_resp: Option<T> = self
-            .cache
-            .lock()
-            .get(&url)
-            .map(|res| serde_json::from_str(res).unwrap());
+        let request = RequestInfo::get(url.clone(), &api_endpoint(&url));
+        let cached = self.cache.lock().get(&url).cloned();
 
-        if let Some(resp) = maybe_resp {
+        if let Some(mut cached) = cached {
+            let resp = de::from_response(&mut cached, &request, self.lenient)?;
             debug!("Found cached: {:?}", resp);
             Ok(resp)
         } else {
             debug!("Nothing in cache. Fetching from league API...");
             // We got nothing in cache, try fetching from utl
-            let url2 = url.clone();
             let header = HeaderValue::from_str(&self.api_key).unwrap();
             let req = Request::builder()
                 .header("X-Riot-Token", header)
-                .uri(url)
+                .uri(url.clone())
                 .body(Body::default())
                 .unwrap();
-            let resp = self.client.request(req).await.context(HyperError)?;
-            let body = resp.into_body();
-            let bytes = hyper::body::to_bytes(body).await.context(HyperError)?;
-            let string_response = String::from_utf8_lossy(&bytes);
+            let mut string_response = fetch(&self.client, req, &request).await?;
             debug!("Deserializing...");
-            let deserialized: T = serde_json::from_str(&string_response).unwrap();
-            self.cache.lock().insert(url2, string_response.into_owned());
+            let deserialized: T = de::from_response(&mut string_response, &request, self.lenient)?;
+            self.cache.lock().insert(url, string_response);
             Ok(deserialized)
         }
     }
@@ -245,6 +259,15 @@ impl Default for LeagueClient {
     }
 }
 
+/// Name of the riot api a url belongs to, e.g. `summoner-v4` for `/lol/summoner/v4/summoners/...`.
+fn api_endpoint(url: &Uri) -> String {
+    let mut parts = url.path().trim_start_matches('/').split('/').skip(1);
+    match (parts.next(), parts.next()) {
+        (Some(api), Some(version)) => format!("{}-{}", api, version),
+        _ => url.path().to_owned(),
+    }
+}
+
 fn check_token(token: &str) -> Result<(), ClientError> {
     ensure!(
         token.contains("RGAPI"),
@@ -263,8 +286,8 @@ fn check_token(token: &str) -> Result<(), ClientError> {
 
 #[cfg(test)]
 mod tests {
-    use super::LeagueClient;
-    use crate::constants::{LanguageCode, RankedQueue, RankedTier, Region};
+    use super::{api_endpoint, LeagueClient};
+    use crate::constants::{Champion, LanguageCode, RankedQueue, RankedTier, Region};
 
     use futures::prelude::*;
     use futures::{Future, FutureExt, TryFutureExt};
@@ -272,7 +295,6 @@ mod tests {
 
     use crate::constants::division::Division;
     use crate::dto::api::{ChampionInfo, ChampionMastery, Summoner};
-    use crate::dto::ddragon::ChampionFullData;
     use crate::error::ClientError;
     use crate::types::Cache;
     use log::debug;
@@ -283,6 +305,12 @@ mod tests {
         debug!("{:?}", cache.lock().keys().collect::<Vec<_>>())
     }
 
+    #[test]
+    fn names_api_endpoints() {
+        let url = "https://euw1.api.riotgames.com/lol/champion-mastery/v4/scores/by-summoner/id";
+        assert_eq!(api_endpoint(&url.parse().unwrap()), "champion-mastery-v4");
+    }
+
     #[test]
     fn gets_summoner_data() {
         smol::run(async {
@@ -290,7 +318,7 @@ mod tests {
             let mut lapi = LeagueClient::new(Region::NA).unwrap();
             let sum = lapi.get_summoner_by_name("Santorin").await.unwrap();
             assert_eq!(
-                &sum.account_id,
+                sum.account_id.as_str(),
                 "rPnj4h5W6OhejxB-AO3hLOQctgZcckqV_82N_8_WuCFdO2A"
             )
         })
@@ -337,15 +365,13 @@ mod tests {
                 .unwrap()
                 .with_ddragon(LanguageCode::UNITED_STATES)
                 .await;
-            let mut ddragon_client = lapi.ddragon();
-            let lee_sin: ChampionFullData = ddragon_client.get_champion("LeeSin").await.unwrap();
             let summoner: Summoner = lapi.get_summoner_by_name("Santorin").await.unwrap();
             let mastery: ChampionMastery = lapi
-                .get_champion_mastery_by_id(&summoner.id, lee_sin.key.parse().unwrap())
+                .get_champion_mastery_by_id(&summoner.id, Champion::LEE_SIN)
                 .await
                 .unwrap();
 
-            assert_eq!(mastery.champion_id, 64);
+            assert_eq!(mastery.champion_id, Champion::LEE_SIN);
             assert_eq!(mastery.champion_level, 7);
             assert!(mastery.champion_points >= 93748)
         })
@@ -0,0 +1,263 @@
+//! Client for [CommunityDragon](https://www.communitydragon.org/) static data.
+//!
+//! CommunityDragon serves data extracted from the game client, which includes assets ddragon lacks,
+//! such as skin lines, chromas and perk icons. [`CDragonClient`] caches responses the same way
+//! [`DDragonClient`] does and can be pointed at a local mirror with [`with_base_url`].
+//!
+//! [`CDragonClient`]: struct.CDragonClient.html
+//! [`DDragonClient`]: ../ddragon/struct.DDragonClient.html
+//! [`with_base_url`]: struct.CDragonClient.html#method.with_base_url
+use crate::constants::LanguageCode;
+use crate::de;
+use crate::dto::cdragon::{CDragonChampion, CDragonChampionSummary, CDragonPerk, CDragonSkin};
+use crate::error::{ClientError, RequestInfo};
+use crate::types::{Cache, Client};
+use crate::utils::{construct_hyper_client, fetch, CachedClient};
+use log::debug;
+
+use hyper::{Request, Uri};
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
+use snafu::ResultExt;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+const RAW_URL: &str = "https://raw.communitydragon.org";
+
+/// Prefix of asset paths inside CommunityDragon json files.
+const ASSET_PREFIX: &str = "/lol-game-data/assets/";
+
+#[derive(Debug, Clone)]
+pub struct CDragonClient {
+    client: Client,
+    cache: Cache,
+    base_url: String,
+    patch: String,
+    locale: String,
+    lenient: bool,
+}
+
+impl CDragonClient {
+    /// Creates a client for the latest patch on `raw.communitydragon.org`.
+    pub fn new(language: LanguageCode) -> CDragonClient {
+        CDragonClient::with_base_url(RAW_URL, "latest", language)
+    }
+
+    /// Creates a client for a specific `patch` (e.g. `10.10` or `latest`) on a custom host,
+    /// for example a local mirror at `http://localhost:8080`.
+    pub fn with_base_url(base_url: &str, patch: &str, language: LanguageCode) -> CDragonClient {
+        CDragonClient {
+            client: construct_hyper_client(),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            patch: patch.to_owned(),
+            locale: cdragon_locale(&language),
+            lenient: false,
+        }
+    }
+
+    /// Drops fields that fail to deserialize with a warning instead of failing the request.
+    ///
+    /// See [`DDragonClient::lenient`](../ddragon/struct.DDragonClient.html#method.lenient).
+    pub fn lenient(self, lenient: bool) -> Self {
+        CDragonClient { lenient, ..self }
+    }
+
+    /// Patch this client fetches data for.
+    pub fn patch(&self) -> &str {
+        &self.patch
+    }
+
+    pub async fn get_champion_summaries(
+        &mut self,
+    ) -> Result<Vec<CDragonChampionSummary>, ClientError> {
+        self.cached_resp(self.game_data_url("v1/champion-summary.json"))
+            .await
+    }
+
+    /// Gets champion data including all skins by numeric champion key, e.g. `64` for Lee Sin.
+    pub async fn get_champion(&mut self, key: i64) -> Result<CDragonChampion, ClientError> {
+        self.cached_resp(self.game_data_url(&format!("v1/champions/{}.json", key)))
+            .await
+    }
+
+    /// Gets all skins keyed by skin id.
+    pub async fn get_skins(&mut self) -> Result<HashMap<String, CDragonSkin>, ClientError> {
+        self.cached_resp(self.game_data_url("v1/skins.json")).await
+    }
+
+    /// Gets all runes.
+    pub async fn get_perks(&mut self) -> Result<Vec<CDragonPerk>, ClientError> {
+        self.cached_resp(self.game_data_url("v1/perks.json")).await
+    }
+
+    /// Converts an asset path found in CommunityDragon data (e.g. a skin's `splash_path`)
+    /// into a downloadable url.
+    pub fn asset_url(&self, path: &str) -> String {
+        match path.strip_prefix(ASSET_PREFIX) {
+            Some(asset) => format!(
+                "{}/{}/plugins/rcp-be-lol-game-data/global/default/{}",
+                self.base_url,
+                self.patch,
+                asset.to_lowercase()
+            ),
+            None => format!(
+                "{}/{}/{}",
+                self.base_url,
+                self.patch,
+                path.trim_start_matches('/')
+            ),
+        }
+    }
+
+    fn game_data_url(&self, path: &str) -> Uri {
+        format!(
+            "{}/{}/plugins/rcp-be-lol-game-data/global/{}/{}",
+            self.base_url, self.patch, self.locale, path
+        )
+        .parse()
+        .unwrap()
+    }
+}
+
+/// CommunityDragon calls english `default` and lowercases other locales, e.g. `ko_kr`.
+fn cdragon_locale(language: &LanguageCode) -> String {
+    if *language == LanguageCode::UNITED_STATES {
+        "default".to_owned()
+    } else {
+        language.as_str().to_lowercase()
+    }
+}
+
+#[async_trait]
+impl CachedClient for CDragonClient {
+    async fn cached_resp<T: Debug + DeserializeOwned + Send>(
+        &self,
+        url: Uri,
+    ) -> Result<T, ClientError> {
+        let request = RequestInfo::get(url.clone(), "cdragon");
+        let cached = self.cache.lock().get(&url).cloned();
+
+        if let Some(mut cached) = cached {
+            let resp = de::from_response(&mut cached, &request, self.lenient)?;
+            debug!("Found cached: {:?}", resp);
+            Ok(resp)
+        } else {
+            debug!("Nothing in cache. Fetching from CommunityDragon...");
+            let req = Request::builder()
+                .uri(url.clone())
+                .body(Default::default())
+                .unwrap();
+            let mut string_response = fetch(&self.client, req, &request).await?;
+            debug!("Deserializing...");
+            let deserialized: T = de::from_response(&mut string_response, &request, self.lenient)?;
+            self.cache.lock().insert(url, string_response);
+            Ok(deserialized)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CDragonClient;
+    use crate::constants::LanguageCode;
+    use crate::error::ClientError;
+    use hyper::StatusCode;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::path::Path;
+    use std::thread;
+
+    /// Serves files from `tests/data/cdragon` over http, mapping the game data path onto the directory.
+    /// Missing files are answered with `404 Not Found`.
+    fn serve_mirror() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request_line = String::new();
+                BufReader::new(&stream)
+                    .read_line(&mut request_line)
+                    .unwrap();
+                let path = request_line.split_whitespace().nth(1).unwrap().to_owned();
+                let file = path.rsplit("/v1/").next().unwrap();
+                let file = Path::new("tests/data/cdragon").join(file);
+                let (status, body) = match std::fs::read(file) {
+                    Ok(body) => ("200 OK", body),
+                    Err(_) => ("404 Not Found", Vec::new()),
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    status,
+                    body.len()
+                )
+                .unwrap();
+                stream.write_all(&body).unwrap();
+            }
+        });
+        format!("http://{}", addr)
+    }
+
+    #[test]
+    fn builds_game_data_and_asset_urls() {
+        let cli =
+            CDragonClient::with_base_url("http://localhost:8080/", "10.10", LanguageCode::KOREA);
+        assert_eq!(
+            cli.game_data_url("v1/skins.json"),
+            "http://localhost:8080/10.10/plugins/rcp-be-lol-game-data/global/ko_kr/v1/skins.json"
+        );
+        assert_eq!(
+            cli.asset_url("/lol-game-data/assets/v1/champion-splashes/64/64011.jpg"),
+            "http://localhost:8080/10.10/plugins/rcp-be-lol-game-data/global/default/v1/champion-splashes/64/64011.jpg"
+        );
+    }
+
+    #[test]
+    fn fetches_data_from_mirror() {
+        let base_url = serve_mirror();
+        smol::run(async {
+            let mut cli =
+                CDragonClient::with_base_url(&base_url, "latest", LanguageCode::UNITED_STATES);
+            let summaries = cli.get_champion_summaries().await.unwrap();
+            assert!(summaries.iter().any(|c| c.alias == "MonkeyKing"));
+
+            let lee_sin = cli.get_champion(64).await.unwrap();
+            assert_eq!(lee_sin.spells.len(), 4);
+            assert_eq!(lee_sin.skins[1].chromas.len(), 2);
+
+            let skins = cli.get_skins().await.unwrap();
+            assert_eq!(skins["1009"].name, "Panda Annie");
+
+            let perks = cli.get_perks().await.unwrap();
+            assert_eq!(perks[1].name, "Electrocute");
+        })
+    }
+
+    #[test]
+    fn separates_api_and_transport_errors() {
+        let base_url = serve_mirror();
+        smol::run(async {
+            let mut cli =
+                CDragonClient::with_base_url(&base_url, "latest", LanguageCode::UNITED_STATES);
+            let err = cli.get_champion(9999).await.unwrap_err();
+            assert!(matches!(err, ClientError::Api { .. }));
+            assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+            assert!(!err.is_retryable());
+            let request = err.request().unwrap();
+            assert_eq!(request.endpoint, "cdragon");
+            assert!(request.url.path().ends_with("/v1/champions/9999.json"));
+
+            let mut offline =
+                CDragonClient::with_base_url("http://127.0.0.1:1", "latest", LanguageCode::KOREA);
+            let err = offline.get_perks().await.unwrap_err();
+            assert!(matches!(err, ClientError::Transport { .. }));
+            assert_eq!(err.status(), None);
+            assert!(err.is_retryable());
+        })
+    }
+}
@@ -0,0 +1,222 @@
+use crate::error::{ClientError, UnknownConstant};
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use snafu::OptionExt;
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+mod generated;
+
+use generated::CHAMPIONS;
+
+/// League champion identified by its numeric key, e.g. `64` for Lee Sin.
+///
+/// There is one constant per champion, named after the ddragon id (`LEE_SIN`, `MONKEY_KING`).
+/// Keys without a constant, such as champions released after the table was generated, become
+/// [`Champion::Unknown`] instead of failing. The table is refreshed with
+/// `cargo run --example generate_champions`.
+///
+/// Champions can only be created through the constants, [`from_key`] and the other lookups, so
+/// every key has exactly one variant and equality, hashing and ordering all follow the key.
+///
+/// [`Champion::Unknown`]: #variant.Unknown
+/// [`from_key`]: #method.from_key
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Champion {
+    /// A champion from the generated table, use the constants to get one
+    Known(KnownChampion),
+    /// A key missing from the generated table, see [`from_key`](#method.from_key)
+    Unknown(UnknownChampion),
+}
+
+/// Key of a champion in the generated table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KnownChampion(i64);
+
+/// Key of a champion missing from the generated table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnknownChampion(i64);
+
+impl Champion {
+    /// Champion for a numeric key, keys missing from the table become [`Champion::Unknown`]
+    ///
+    /// [`Champion::Unknown`]: #variant.Unknown
+    pub fn from_key(key: i64) -> Champion {
+        match CHAMPIONS.binary_search_by_key(&key, |&(key, _, _)| key) {
+            Ok(_) => Champion::Known(KnownChampion(key)),
+            Err(_) => Champion::Unknown(UnknownChampion(key)),
+        }
+    }
+
+    /// Numeric champion key as used by the riot api
+    #[inline]
+    pub fn key(&self) -> i64 {
+        match *self {
+            Champion::Known(KnownChampion(key)) | Champion::Unknown(UnknownChampion(key)) => key,
+        }
+    }
+
+    /// Whether the champion is in the generated table
+    #[inline]
+    pub fn is_known(&self) -> bool {
+        matches!(self, Champion::Known(_))
+    }
+
+    /// Ddragon id, e.g. `MonkeyKing`, `None` for unknown champions
+    pub fn id(&self) -> Option<&'static str> {
+        self.entry().map(|&(_, id, _)| id)
+    }
+
+    /// English display name, e.g. `Wukong`, `None` for unknown champions
+    pub fn name(&self) -> Option<&'static str> {
+        self.entry().map(|&(_, _, name)| name)
+    }
+
+    /// Champion for a ddragon id, ignoring case
+    pub fn from_id(id: &str) -> Option<Champion> {
+        CHAMPIONS
+            .iter()
+            .find(|(_, champion_id, _)| champion_id.eq_ignore_ascii_case(id))
+            .map(|&(key, _, _)| Champion::Known(KnownChampion(key)))
+    }
+
+    /// Iterates over all champions in the generated table, sorted by key
+    pub fn all() -> impl Iterator<Item = Champion> {
+        CHAMPIONS
+            .iter()
+            .map(|&(key, _, _)| Champion::Known(KnownChampion(key)))
+    }
+
+    fn entry(&self) -> Option<&'static (i64, &'static str, &'static str)> {
+        let key = match *self {
+            Champion::Known(KnownChampion(key)) => key,
+            Champion::Unknown(_) => return None,
+        };
+        CHAMPIONS
+            .binary_search_by_key(&key, |&(key, _, _)| key)
+            .ok()
+            .map(|i| &CHAMPIONS[i])
+    }
+}
+
+impl PartialOrd for Champion {
+    fn partial_cmp(&self, other: &Champion) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Champions are ordered by key.
+impl Ord for Champion {
+    fn cmp(&self, other: &Champion) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl From<i64> for Champion {
+    #[inline]
+    fn from(key: i64) -> Champion {
+        Champion::from_key(key)
+    }
+}
+
+impl From<Champion> for i64 {
+    #[inline]
+    fn from(champion: Champion) -> i64 {
+        champion.key()
+    }
+}
+
+/// Parses ddragon ids ignoring case, e.g. `leesin`.
+impl FromStr for Champion {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<Champion, ClientError> {
+        Champion::from_id(s).context(UnknownConstant {
+            kind: "champion",
+            value: s,
+        })
+    }
+}
+
+impl Serialize for Champion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for Champion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Champion, D::Error> {
+        i64::deserialize(deserializer).map(Champion::from_key)
+    }
+}
+
+impl fmt::Debug for Champion {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.id() {
+            Some(id) => write!(fmt, "Champion({})", id),
+            None => write!(fmt, "Champion({})", self.key()),
+        }
+    }
+}
+
+/// Formats the display name, or `Champion <key>` for unknown champions.
+impl fmt::Display for Champion {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => fmt.write_str(name),
+            None => write!(fmt, "Champion {}", self.key()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Champion, CHAMPIONS};
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    #[test]
+    fn looks_up_generated_champions() {
+        assert_eq!(Champion::LEE_SIN.key(), 64);
+        assert_eq!(Champion::MONKEY_KING.name(), Some("Wukong"));
+        assert_eq!(Champion::from_id("jarvaniv"), Some(Champion::JARVAN_IV));
+        assert_eq!("KSante".parse::<Champion>().unwrap(), Champion::KSANTE);
+        assert!(CHAMPIONS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn keeps_unknown_champions() {
+        let champion: Champion = serde_json::from_str("9999").unwrap();
+        assert_eq!(champion, Champion::from_key(9999));
+        assert!(!champion.is_known());
+        assert_eq!(champion.id(), None);
+        assert_eq!(Champion::from_key(64), Champion::LEE_SIN);
+        assert!(Champion::ANNIE < champion);
+        assert_eq!(champion.to_string(), "Champion 9999");
+        assert_eq!(serde_json::to_string(&Champion::ANNIE).unwrap(), "1");
+    }
+
+    #[test]
+    fn equality_hashing_and_ordering_follow_the_key() {
+        let hash = |champion: &Champion| {
+            let mut hasher = DefaultHasher::new();
+            champion.hash(&mut hasher);
+            hasher.finish()
+        };
+        let champions: Vec<Champion> = [1, 62, 64, 9999, -1]
+            .iter()
+            .map(|&key| Champion::from_key(key))
+            .collect();
+        assert!(Champion::from_key(62).is_known());
+        for a in &champions {
+            let same = Champion::from_key(a.key());
+            assert_eq!(*a, same);
+            assert_eq!(hash(a), hash(&same));
+            for b in &champions {
+                assert_eq!(a == b, a.cmp(b) == std::cmp::Ordering::Equal);
+                assert_eq!(a.cmp(b), a.key().cmp(&b.key()));
+            }
+        }
+    }
+}
@@ -0,0 +1,519 @@
+// Generated by `cargo run --example generate_champions` from ddragon 15.2.1, do not edit.
+use super::{Champion, KnownChampion};
+
+impl Champion {
+    /// Annie
+    pub const ANNIE: Champion = Champion::Known(KnownChampion(1));
+    /// Olaf
+    pub const OLAF: Champion = Champion::Known(KnownChampion(2));
+    /// Galio
+    pub const GALIO: Champion = Champion::Known(KnownChampion(3));
+    /// Twisted Fate
+    pub const TWISTED_FATE: Champion = Champion::Known(KnownChampion(4));
+    /// Xin Zhao
+    pub const XIN_ZHAO: Champion = Champion::Known(KnownChampion(5));
+    /// Urgot
+    pub const URGOT: Champion = Champion::Known(KnownChampion(6));
+    /// LeBlanc
+    pub const LEBLANC: Champion = Champion::Known(KnownChampion(7));
+    /// Vladimir
+    pub const VLADIMIR: Champion = Champion::Known(KnownChampion(8));
+    /// Fiddlesticks
+    pub const FIDDLESTICKS: Champion = Champion::Known(KnownChampion(9));
+    /// Kayle
+    pub const KAYLE: Champion = Champion::Known(KnownChampion(10));
+    /// Master Yi
+    pub const MASTER_YI: Champion = Champion::Known(KnownChampion(11));
+    /// Alistar
+    pub const ALISTAR: Champion = Champion::Known(KnownChampion(12));
+    /// Ryze
+    pub const RYZE: Champion = Champion::Known(KnownChampion(13));
+    /// Sion
+    pub const SION: Champion = Champion::Known(KnownChampion(14));
+    /// Sivir
+    pub const SIVIR: Champion = Champion::Known(KnownChampion(15));
+    /// Soraka
+    pub const SORAKA: Champion = Champion::Known(KnownChampion(16));
+    /// Teemo
+    pub const TEEMO: Champion = Champion::Known(KnownChampion(17));
+    /// Tristana
+    pub const TRISTANA: Champion = Champion::Known(KnownChampion(18));
+    /// Warwick
+    pub const WARWICK: Champion = Champion::Known(KnownChampion(19));
+    /// Nunu & Willump
+    pub const NUNU: Champion = Champion::Known(KnownChampion(20));
+    /// Miss Fortune
+    pub const MISS_FORTUNE: Champion = Champion::Known(KnownChampion(21));
+    /// Ashe
+    pub const ASHE: Champion = Champion::Known(KnownChampion(22));
+    /// Tryndamere
+    pub const TRYNDAMERE: Champion = Champion::Known(KnownChampion(23));
+    /// Jax
+    pub const JAX: Champion = Champion::Known(KnownChampion(24));
+    /// Morgana
+    pub const MORGANA: Champion = Champion::Known(KnownChampion(25));
+    /// Zilean
+    pub const ZILEAN: Champion = Champion::Known(KnownChampion(26));
+    /// Singed
+    pub const SINGED: Champion = Champion::Known(KnownChampion(27));
+    /// Evelynn
+    pub const EVELYNN: Champion = Champion::Known(KnownChampion(28));
+    /// Twitch
+    pub const TWITCH: Champion = Champion::Known(KnownChampion(29));
+    /// Karthus
+    pub const KARTHUS: Champion = Champion::Known(KnownChampion(30));
+    /// Cho'Gath
+    pub const CHOGATH: Champion = Champion::Known(KnownChampion(31));
+    /// Amumu
+    pub const AMUMU: Champion = Champion::Known(KnownChampion(32));
+    /// Rammus
+    pub const RAMMUS: Champion = Champion::Known(KnownChampion(33));
+    /// Anivia
+    pub const ANIVIA: Champion = Champion::Known(KnownChampion(34));
+    /// Shaco
+    pub const SHACO: Champion = Champion::Known(KnownChampion(35));
+    /// Dr. Mundo
+    pub const DR_MUNDO: Champion = Champion::Known(KnownChampion(36));
+    /// Sona
+    pub const SONA: Champion = Champion::Known(KnownChampion(37));
+    /// Kassadin
+    pub const KASSADIN: Champion = Champion::Known(KnownChampion(38));
+    /// Irelia
+    pub const IRELIA: Champion = Champion::Known(KnownChampion(39));
+    /// Janna
+    pub const JANNA: Champion = Champion::Known(KnownChampion(40));
+    /// Gangplank
+    pub const GANGPLANK: Champion = Champion::Known(KnownChampion(41));
+    /// Corki
+    pub const CORKI: Champion = Champion::Known(KnownChampion(42));
+    /// Karma
+    pub const KARMA: Champion = Champion::Known(KnownChampion(43));
+    /// Taric
+    pub const TARIC: Champion = Champion::Known(KnownChampion(44));
+    /// Veigar
+    pub const VEIGAR: Champion = Champion::Known(KnownChampion(45));
+    /// Trundle
+    pub const TRUNDLE: Champion = Champion::Known(KnownChampion(48));
+    /// Swain
+    pub const SWAIN: Champion = Champion::Known(KnownChampion(50));
+    /// Caitlyn
+    pub const CAITLYN: Champion = Champion::Known(KnownChampion(51));
+    /// Blitzcrank
+    pub const BLITZCRANK: Champion = Champion::Known(KnownChampion(53));
+    /// Malphite
+    pub const MALPHITE: Champion = Champion::Known(KnownChampion(54));
+    /// Katarina
+    pub const KATARINA: Champion = Champion::Known(KnownChampion(55));
+    /// Nocturne
+    pub const NOCTURNE: Champion = Champion::Known(KnownChampion(56));
+    /// Maokai
+    pub const MAOKAI: Champion = Champion::Known(KnownChampion(57));
+    /// Renekton
+    pub const RENEKTON: Champion = Champion::Known(KnownChampion(58));
+    /// Jarvan IV
+    pub const JARVAN_IV: Champion = Champion::Known(KnownChampion(59));
+    /// Elise
+    pub const ELISE: Champion = Champion::Known(KnownChampion(60));
+    /// Orianna
+    pub const ORIANNA: Champion = Champion::Known(KnownChampion(61));
+    /// Wukong
+    pub const MONKEY_KING: Champion = Champion::Known(KnownChampion(62));
+    /// Brand
+    pub const BRAND: Champion = Champion::Known(KnownChampion(63));
+    /// Lee Sin
+    pub const LEE_SIN: Champion = Champion::Known(KnownChampion(64));
+    /// Vayne
+    pub const VAYNE: Champion = Champion::Known(KnownChampion(67));
+    /// Rumble
+    pub const RUMBLE: Champion = Champion::Known(KnownChampion(68));
+    /// Cassiopeia
+    pub const CASSIOPEIA: Champion = Champion::Known(KnownChampion(69));
+    /// Skarner
+    pub const SKARNER: Champion = Champion::Known(KnownChampion(72));
+    /// Heimerdinger
+    pub const HEIMERDINGER: Champion = Champion::Known(KnownChampion(74));
+    /// Nasus
+    pub const NASUS: Champion = Champion::Known(KnownChampion(75));
+    /// Nidalee
+    pub const NIDALEE: Champion = Champion::Known(KnownChampion(76));
+    /// Udyr
+    pub const UDYR: Champion = Champion::Known(KnownChampion(77));
+    /// Poppy
+    pub const POPPY: Champion = Champion::Known(KnownChampion(78));
+    /// Gragas
+    pub const GRAGAS: Champion = Champion::Known(KnownChampion(79));
+    /// Pantheon
+    pub const PANTHEON: Champion = Champion::Known(KnownChampion(80));
+    /// Ezreal
+    pub const EZREAL: Champion = Champion::Known(KnownChampion(81));
+    /// Mordekaiser
+    pub const MORDEKAISER: Champion = Champion::Known(KnownChampion(82));
+    /// Yorick
+    pub const YORICK: Champion = Champion::Known(KnownChampion(83));
+    /// Akali
+    pub const AKALI: Champion = Champion::Known(KnownChampion(84));
+    /// Kennen
+    pub const KENNEN: Champion = Champion::Known(KnownChampion(85));
+    /// Garen
+    pub const GAREN: Champion = Champion::Known(KnownChampion(86));
+    /// Leona
+    pub const LEONA: Champion = Champion::Known(KnownChampion(89));
+    /// Malzahar
+    pub const MALZAHAR: Champion = Champion::Known(KnownChampion(90));
+    /// Talon
+    pub const TALON: Champion = Champion::Known(KnownChampion(91));
+    /// Riven
+    pub const RIVEN: Champion = Champion::Known(KnownChampion(92));
+    /// Kog'Maw
+    pub const KOG_MAW: Champion = Champion::Known(KnownChampion(96));
+    /// Shen
+    pub const SHEN: Champion = Champion::Known(KnownChampion(98));
+    /// Lux
+    pub const LUX: Champion = Champion::Known(KnownChampion(99));
+    /// Xerath
+    pub const XERATH: Champion = Champion::Known(KnownChampion(101));
+    /// Shyvana
+    pub const SHYVANA: Champion = Champion::Known(KnownChampion(102));
+    /// Ahri
+    pub const AHRI: Champion = Champion::Known(KnownChampion(103));
+    /// Graves
+    pub const GRAVES: Champion = Champion::Known(KnownChampion(104));
+    /// Fizz
+    pub const FIZZ: Champion = Champion::Known(KnownChampion(105));
+    /// Volibear
+    pub const VOLIBEAR: Champion = Champion::Known(KnownChampion(106));
+    /// Rengar
+    pub const RENGAR: Champion = Champion::Known(KnownChampion(107));
+    /// Varus
+    pub const VARUS: Champion = Champion::Known(KnownChampion(110));
+    /// Nautilus
+    pub const NAUTILUS: Champion = Champion::Known(KnownChampion(111));
+    /// Viktor
+    pub const VIKTOR: Champion = Champion::Known(KnownChampion(112));
+    /// Sejuani
+    pub const SEJUANI: Champion = Champion::Known(KnownChampion(113));
+    /// Fiora
+    pub const FIORA: Champion = Champion::Known(KnownChampion(114));
+    /// Ziggs
+    pub const ZIGGS: Champion = Champion::Known(KnownChampion(115));
+    /// Lulu
+    pub const LULU: Champion = Champion::Known(KnownChampion(117));
+    /// Draven
+    pub const DRAVEN: Champion = Champion::Known(KnownChampion(119));
+    /// Hecarim
+    pub const HECARIM: Champion = Champion::Known(KnownChampion(120));
+    /// Kha'Zix
+    pub const KHAZIX: Champion = Champion::Known(KnownChampion(121));
+    /// Darius
+    pub const DARIUS: Champion = Champion::Known(KnownChampion(122));
+    /// Jayce
+    pub const JAYCE: Champion = Champion::Known(KnownChampion(126));
+    /// Lissandra
+    pub const LISSANDRA: Champion = Champion::Known(KnownChampion(127));
+    /// Diana
+    pub const DIANA: Champion = Champion::Known(KnownChampion(131));
+    /// Quinn
+    pub const QUINN: Champion = Champion::Known(KnownChampion(133));
+    /// Syndra
+    pub const SYNDRA: Champion = Champion::Known(KnownChampion(134));
+    /// Aurelion Sol
+    pub const AURELION_SOL: Champion = Champion::Known(KnownChampion(136));
+    /// Kayn
+    pub const KAYN: Champion = Champion::Known(KnownChampion(141));
+    /// Zoe
+    pub const ZOE: Champion = Champion::Known(KnownChampion(142));
+    /// Zyra
+    pub const ZYRA: Champion = Champion::Known(KnownChampion(143));
+    /// Kai'Sa
+    pub const KAISA: Champion = Champion::Known(KnownChampion(145));
+    /// Seraphine
+    pub const SERAPHINE: Champion = Champion::Known(KnownChampion(147));
+    /// Gnar
+    pub const GNAR: Champion = Champion::Known(KnownChampion(150));
+    /// Zac
+    pub const ZAC: Champion = Champion::Known(KnownChampion(154));
+    /// Yasuo
+    pub const YASUO: Champion = Champion::Known(KnownChampion(157));
+    /// Vel'Koz
+    pub const VELKOZ: Champion = Champion::Known(KnownChampion(161));
+    /// Taliyah
+    pub const TALIYAH: Champion = Champion::Known(KnownChampion(163));
+    /// Camille
+    pub const CAMILLE: Champion = Champion::Known(KnownChampion(164));
+    /// Akshan
+    pub const AKSHAN: Champion = Champion::Known(KnownChampion(166));
+    /// Bel'Veth
+    pub const BELVETH: Champion = Champion::Known(KnownChampion(200));
+    /// Braum
+    pub const BRAUM: Champion = Champion::Known(KnownChampion(201));
+    /// Jhin
+    pub const JHIN: Champion = Champion::Known(KnownChampion(202));
+    /// Kindred
+    pub const KINDRED: Champion = Champion::Known(KnownChampion(203));
+    /// Zeri
+    pub const ZERI: Champion = Champion::Known(KnownChampion(221));
+    /// Jinx
+    pub const JINX: Champion = Champion::Known(KnownChampion(222));
+    /// Tahm Kench
+    pub const TAHM_KENCH: Champion = Champion::Known(KnownChampion(223));
+    /// Briar
+    pub const BRIAR: Champion = Champion::Known(KnownChampion(233));
+    /// Viego
+    pub const VIEGO: Champion = Champion::Known(KnownChampion(234));
+    /// Senna
+    pub const SENNA: Champion = Champion::Known(KnownChampion(235));
+    /// Lucian
+    pub const LUCIAN: Champion = Champion::Known(KnownChampion(236));
+    /// Zed
+    pub const ZED: Champion = Champion::Known(KnownChampion(238));
+    /// Kled
+    pub const KLED: Champion = Champion::Known(KnownChampion(240));
+    /// Ekko
+    pub const EKKO: Champion = Champion::Known(KnownChampion(245));
+    /// Qiyana
+    pub const QIYANA: Champion = Champion::Known(KnownChampion(246));
+    /// Vi
+    pub const VI: Champion = Champion::Known(KnownChampion(254));
+    /// Aatrox
+    pub const AATROX: Champion = Champion::Known(KnownChampion(266));
+    /// Nami
+    pub const NAMI: Champion = Champion::Known(KnownChampion(267));
+    /// Azir
+    pub const AZIR: Champion = Champion::Known(KnownChampion(268));
+    /// Yuumi
+    pub const YUUMI: Champion = Champion::Known(KnownChampion(350));
+    /// Samira
+    pub const SAMIRA: Champion = Champion::Known(KnownChampion(360));
+    /// Thresh
+    pub const THRESH: Champion = Champion::Known(KnownChampion(412));
+    /// Illaoi
+    pub const ILLAOI: Champion = Champion::Known(KnownChampion(420));
+    /// Rek'Sai
+    pub const REK_SAI: Champion = Champion::Known(KnownChampion(421));
+    /// Ivern
+    pub const IVERN: Champion = Champion::Known(KnownChampion(427));
+    /// Kalista
+    pub const KALISTA: Champion = Champion::Known(KnownChampion(429));
+    /// Bard
+    pub const BARD: Champion = Champion::Known(KnownChampion(432));
+    /// Rakan
+    pub const RAKAN: Champion = Champion::Known(KnownChampion(497));
+    /// Xayah
+    pub const XAYAH: Champion = Champion::Known(KnownChampion(498));
+    /// Ornn
+    pub const ORNN: Champion = Champion::Known(KnownChampion(516));
+    /// Sylas
+    pub const SYLAS: Champion = Champion::Known(KnownChampion(517));
+    /// Neeko
+    pub const NEEKO: Champion = Champion::Known(KnownChampion(518));
+    /// Aphelios
+    pub const APHELIOS: Champion = Champion::Known(KnownChampion(523));
+    /// Rell
+    pub const RELL: Champion = Champion::Known(KnownChampion(526));
+    /// Pyke
+    pub const PYKE: Champion = Champion::Known(KnownChampion(555));
+    /// Vex
+    pub const VEX: Champion = Champion::Known(KnownChampion(711));
+    /// Yone
+    pub const YONE: Champion = Champion::Known(KnownChampion(777));
+    /// Ambessa
+    pub const AMBESSA: Champion = Champion::Known(KnownChampion(799));
+    /// Mel
+    pub const MEL: Champion = Champion::Known(KnownChampion(800));
+    /// Sett
+    pub const SETT: Champion = Champion::Known(KnownChampion(875));
+    /// Lillia
+    pub const LILLIA: Champion = Champion::Known(KnownChampion(876));
+    /// Gwen
+    pub const GWEN: Champion = Champion::Known(KnownChampion(887));
+    /// Renata Glasc
+    pub const RENATA: Champion = Champion::Known(KnownChampion(888));
+    /// Aurora
+    pub const AURORA: Champion = Champion::Known(KnownChampion(893));
+    /// Nilah
+    pub const NILAH: Champion = Champion::Known(KnownChampion(895));
+    /// K'Sante
+    pub const KSANTE: Champion = Champion::Known(KnownChampion(897));
+    /// Smolder
+    pub const SMOLDER: Champion = Champion::Known(KnownChampion(901));
+    /// Milio
+    pub const MILIO: Champion = Champion::Known(KnownChampion(902));
+    /// Hwei
+    pub const HWEI: Champion = Champion::Known(KnownChampion(910));
+    /// Naafiri
+    pub const NAAFIRI: Champion = Champion::Known(KnownChampion(950));
+}
+
+/// `(key, ddragon id, name)` of every champion, sorted by key.
+pub(super) const CHAMPIONS: &[(i64, &str, &str)] = &[
+    (1, "Annie", "Annie"),
+    (2, "Olaf", "Olaf"),
+    (3, "Galio", "Galio"),
+    (4, "TwistedFate", "Twisted Fate"),
+    (5, "XinZhao", "Xin Zhao"),
+    (6, "Urgot", "Urgot"),
+    (7, "Leblanc", "LeBlanc"),
+    (8, "Vladimir", "Vladimir"),
+    (9, "Fiddlesticks", "Fiddlesticks"),
+    (10, "Kayle", "Kayle"),
+    (11, "MasterYi", "Master Yi"),
+    (12, "Alistar", "Alistar"),
+    (13, "Ryze", "Ryze"),
+    (14, "Sion", "Sion"),
+    (15, "Sivir", "Sivir"),
+    (16, "Soraka", "Soraka"),
+    (17, "Teemo", "Teemo"),
+    (18, "Tristana", "Tristana"),
+    (19, "Warwick", "Warwick"),
+    (20, "Nunu", "Nunu & Willump"),
+    (21, "MissFortune", "Miss Fortune"),
+    (22, "Ashe", "Ashe"),
+    (23, "Tryndamere", "Tryndamere"),
+    (24, "Jax", "Jax"),
+    (25, "Morgana", "Morgana"),
+    (26, "Zilean", "Zilean"),
+    (27, "Singed", "Singed"),
+    (28, "Evelynn", "Evelynn"),
+    (29, "Twitch", "Twitch"),
+    (30, "Karthus", "Karthus"),
+    (31, "Chogath", "Cho'Gath"),
+    (32, "Amumu", "Amumu"),
+    (33, "Rammus", "Rammus"),
+    (34, "Anivia", "Anivia"),
+    (35, "Shaco", "Shaco"),
+    (36, "DrMundo", "Dr. Mundo"),
+    (37, "Sona", "Sona"),
+    (38, "Kassadin", "Kassadin"),
+    (39, "Irelia", "Irelia"),
+    (40, "Janna", "Janna"),
+    (41, "Gangplank", "Gangplank"),
+    (42, "Corki", "Corki"),
+    (43, "Karma", "Karma"),
+    (44, "Taric", "Taric"),
+    (45, "Veigar", "Veigar"),
+    (48, "Trundle", "Trundle"),
+    (50, "Swain", "Swain"),
+    (51, "Caitlyn", "Caitlyn"),
+    (53, "Blitzcrank", "Blitzcrank"),
+    (54, "Malphite", "Malphite"),
+    (55, "Katarina", "Katarina"),
+    (56, "Nocturne", "Nocturne"),
+    (57, "Maokai", "Maokai"),
+    (58, "Renekton", "Renekton"),
+    (59, "JarvanIV", "Jarvan IV"),
+    (60, "Elise", "Elise"),
+    (61, "Orianna", "Orianna"),
+    (62, "MonkeyKing", "Wukong"),
+    (63, "Brand", "Brand"),
+    (64, "LeeSin", "Lee Sin"),
+    (67, "Vayne", "Vayne"),
+    (68, "Rumble", "Rumble"),
+    (69, "Cassiopeia", "Cassiopeia"),
+    (72, "Skarner", "Skarner"),
+    (74, "Heimerdinger", "Heimerdinger"),
+    (75, "Nasus", "Nasus"),
+    (76, "Nidalee", "Nidalee"),
+    (77, "Udyr", "Udyr"),
+    (78, "Poppy", "Poppy"),
+    (79, "Gragas", "Gragas"),
+    (80, "Pantheon", "Pantheon"),
+    (81, "Ezreal", "Ezreal"),
+    (82, "Mordekaiser", "Mordekaiser"),
+    (83, "Yorick", "Yorick"),
+    (84, "Akali", "Akali"),
+    (85, "Kennen", "Kennen"),
+    (86, "Garen", "Garen"),
+    (89, "Leona", "Leona"),
+    (90, "Malzahar", "Malzahar"),
+    (91, "Talon", "Talon"),
+    (92, "Riven", "Riven"),
+    (96, "KogMaw", "Kog'Maw"),
+    (98, "Shen", "Shen"),
+    (99, "Lux", "Lux"),
+    (101, "Xerath", "Xerath"),
+    (102, "Shyvana", "Shyvana"),
+    (103, "Ahri", "Ahri"),
+    (104, "Graves", "Graves"),
+    (105, "Fizz", "Fizz"),
+    (106, "Volibear", "Volibear"),
+    (107, "Rengar", "Rengar"),
+    (110, "Varus", "Varus"),
+    (111, "Nautilus", "Nautilus"),
+    (112, "Viktor", "Viktor"),
+    (113, "Sejuani", "Sejuani"),
+    (114, "Fiora", "Fiora"),
+    (115, "Ziggs", "Ziggs"),
+    (117, "Lulu", "Lulu"),
+    (119, "Draven", "Draven"),
+    (120, "Hecarim", "Hecarim"),
+    (121, "Khazix", "Kha'Zix"),
+    (122, "Darius", "Darius"),
+    (126, "Jayce", "Jayce"),
+    (127, "Lissandra", "Lissandra"),
+    (131, "Diana", "Diana"),
+    (133, "Quinn", "Quinn"),
+    (134, "Syndra", "Syndra"),
+    (136, "AurelionSol", "Aurelion Sol"),
+    (141, "Kayn", "Kayn"),
+    (142, "Zoe", "Zoe"),
+    (143, "Zyra", "Zyra"),
+    (145, "Kaisa", "Kai'Sa"),
+    (147, "Seraphine", "Seraphine"),
+    (150, "Gnar", "Gnar"),
+    (154, "Zac", "Zac"),
+    (157, "Yasuo", "Yasuo"),
+    (161, "Velkoz", "Vel'Koz"),
+    (163, "Taliyah", "Taliyah"),
+    (164, "Camille", "Camille"),
+    (166, "Akshan", "Akshan"),
+    (200, "Belveth", "Bel'Veth"),
+    (201, "Braum", "Braum"),
+    (202, "Jhin", "Jhin"),
+    (203, "Kindred", "Kindred"),
+    (221, "Zeri", "Zeri"),
+    (222, "Jinx", "Jinx"),
+    (223, "TahmKench", "Tahm Kench"),
+    (233, "Briar", "Briar"),
+    (234, "Viego", "Viego"),
+    (235, "Senna", "Senna"),
+    (236, "Lucian", "Lucian"),
+    (238, "Zed", "Zed"),
+    (240, "Kled", "Kled"),
+    (245, "Ekko", "Ekko"),
+    (246, "Qiyana", "Qiyana"),
+    (254, "Vi", "Vi"),
+    (266, "Aatrox", "Aatrox"),
+    (267, "Nami", "Nami"),
+    (268, "Azir", "Azir"),
+    (350, "Yuumi", "Yuumi"),
+    (360, "Samira", "Samira"),
+    (412, "Thresh", "Thresh"),
+    (420, "Illaoi", "Illaoi"),
+    (421, "RekSai", "Rek'Sai"),
+    (427, "Ivern", "Ivern"),
+    (429, "Kalista", "Kalista"),
+    (432, "Bard", "Bard"),
+    (497, "Rakan", "Rakan"),
+    (498, "Xayah", "Xayah"),
+    (516, "Ornn", "Ornn"),
+    (517, "Sylas", "Sylas"),
+    (518, "Neeko", "Neeko"),
+    (523, "Aphelios", "Aphelios"),
+    (526, "Rell", "Rell"),
+    (555, "Pyke", "Pyke"),
+    (711, "Vex", "Vex"),
+    (777, "Yone", "Yone"),
+    (799, "Ambessa", "Ambessa"),
+    (800, "Mel", "Mel"),
+    (875, "Sett", "Sett"),
+    (876, "Lillia", "Lillia"),
+    (887, "Gwen", "Gwen"),
+    (888, "Renata", "Renata Glasc"),
+    (893, "Aurora", "Aurora"),
+    (895, "Nilah", "Nilah"),
+    (897, "KSante", "K'Sante"),
+    (901, "Smolder", "Smolder"),
+    (902, "Milio", "Milio"),
+    (910, "Hwei", "Hwei"),
+    (950, "Naafiri", "Naafiri"),
+];
@@ -0,0 +1,151 @@
+use self::Inner::*;
+use crate::error::{ClientError, UnknownConstant};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use snafu::OptionExt;
+use std::convert::AsRef;
+use std::fmt;
+use std::str::FromStr;
+
+/// Champion class as found in ddragon `tags`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ChampionTag(Inner);
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Inner {
+    Fighter,
+    Tank,
+    Mage,
+    Assassin,
+    Support,
+    Marksman,
+}
+
+const ALL: [ChampionTag; 6] = [
+    ChampionTag::FIGHTER,
+    ChampionTag::TANK,
+    ChampionTag::MAGE,
+    ChampionTag::ASSASSIN,
+    ChampionTag::SUPPORT,
+    ChampionTag::MARKSMAN,
+];
+
+impl ChampionTag {
+    /// Fighter
+    pub const FIGHTER: ChampionTag = ChampionTag(Fighter);
+    /// Tank
+    pub const TANK: ChampionTag = ChampionTag(Tank);
+    /// Mage
+    pub const MAGE: ChampionTag = ChampionTag(Mage);
+    /// Assassin
+    pub const ASSASSIN: ChampionTag = ChampionTag(Assassin);
+    /// Support
+    pub const SUPPORT: ChampionTag = ChampionTag(Support);
+    /// Marksman
+    pub const MARKSMAN: ChampionTag = ChampionTag(Marksman);
+
+    /// Ddragon name of a champion tag
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self.0 {
+            Fighter => "Fighter",
+            Tank => "Tank",
+            Mage => "Mage",
+            Assassin => "Assassin",
+            Support => "Support",
+            Marksman => "Marksman",
+        }
+    }
+
+    /// Iterates over all champion tag values
+    pub fn all() -> impl Iterator<Item = ChampionTag> {
+        ALL.iter().cloned()
+    }
+}
+
+impl AsRef<str> for ChampionTag {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for ChampionTag {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl PartialEq<&str> for ChampionTag {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_ref() == *other
+    }
+}
+
+impl PartialEq<ChampionTag> for str {
+    #[inline]
+    fn eq(&self, other: &ChampionTag) -> bool {
+        self == other.as_ref()
+    }
+}
+
+impl fmt::Debug for ChampionTag {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+impl fmt::Display for ChampionTag {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+impl FromStr for ChampionTag {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<ChampionTag, ClientError> {
+        ChampionTag::all()
+            .find(|value| value.as_str().eq_ignore_ascii_case(s))
+            .context(UnknownConstant {
+                kind: "champion tag",
+                value: s,
+            })
+    }
+}
+
+impl Serialize for ChampionTag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChampionTag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ChampionTag, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChampionTag;
+
+    #[test]
+    fn parses_tag_names() {
+        assert_eq!("Tank".parse::<ChampionTag>().unwrap(), ChampionTag::TANK);
+        assert_eq!("tank".parse::<ChampionTag>().unwrap(), ChampionTag::TANK);
+        assert!("Jungler".parse::<ChampionTag>().is_err());
+        assert_eq!(ChampionTag::MARKSMAN, "Marksman")
+    }
+
+    #[test]
+    fn champion_tag_round_trips_through_serde() {
+        let tag: ChampionTag = serde_json::from_str("\"mage\"").unwrap();
+        assert_eq!(tag, ChampionTag::MAGE);
+        assert_eq!(serde_json::to_string(&tag).unwrap(), "\"Mage\"");
+        assert_eq!(ChampionTag::all().count(), 6);
+    }
+}
@@ -1,5 +1,11 @@
+use crate::error::{ClientError, UnknownConstant};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use snafu::OptionExt;
+use std::cmp::Ordering;
 use std::convert::AsRef;
 use std::fmt::{self, Debug};
+use std::str::FromStr;
 use Inner::*;
 
 #[derive(Debug, Clone, Eq)]
@@ -13,6 +19,8 @@ enum Inner {
     IV,
 }
 
+const ALL: [Division; 4] = [Division::I, Division::II, Division::III, Division::IV];
+
 impl Division {
     pub const I: Division = Division(I);
 
@@ -31,6 +39,22 @@ impl Division {
             IV => "IV",
         }
     }
+
+    /// Numeric value of the division, `1` for `I` and `4` for `IV`
+    #[inline]
+    pub fn number(&self) -> u8 {
+        match self.0 {
+            I => 1,
+            II => 2,
+            III => 3,
+            IV => 4,
+        }
+    }
+
+    /// Iterates over all division values
+    pub fn all() -> impl Iterator<Item = Division> {
+        ALL.iter().cloned()
+    }
 }
 
 impl AsRef<str> for Division {
@@ -56,7 +80,22 @@ impl<'a> PartialEq<Division> for &'a Division {
 impl PartialEq<Division> for Division {
     #[inline]
     fn eq(&self, other: &Division) -> bool {
-        self == other
+        self.0 == other.0
+    }
+}
+
+/// Divisions are ordered from `IV` (lowest) to `I` (highest).
+impl PartialOrd for Division {
+    #[inline]
+    fn partial_cmp(&self, other: &Division) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Division {
+    #[inline]
+    fn cmp(&self, other: &Division) -> Ordering {
+        other.number().cmp(&self.number())
     }
 }
 
@@ -87,6 +126,32 @@ impl fmt::Display for Division {
     }
 }
 
+impl FromStr for Division {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<Division, ClientError> {
+        Division::all()
+            .find(|value| value.as_str().eq_ignore_ascii_case(s))
+            .context(UnknownConstant {
+                kind: "division",
+                value: s,
+            })
+    }
+}
+
+impl Serialize for Division {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Division {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Division, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
 mod tests {
     use super::Division;
 
@@ -95,4 +160,16 @@ mod tests {
         let division = Division::III;
         assert_eq!(division.as_str(), "III")
     }
+
+    #[test]
+    fn higher_divisions_compare_greater() {
+        assert!(Division::I > Division::II);
+        assert_eq!(Division::all().max(), Some(Division::I));
+    }
+
+    #[test]
+    fn parses_divisions() {
+        assert_eq!("iv".parse::<Division>().unwrap(), Division::IV);
+        assert_eq!(Division::all().count(), 4);
+    }
 }
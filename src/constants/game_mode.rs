@@ -0,0 +1,230 @@
+use self::Inner::*;
+use crate::error::{ClientError, UnknownConstant};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use snafu::OptionExt;
+use std::convert::AsRef;
+use std::fmt;
+use std::str::FromStr;
+
+/// Game mode as found in match `gameMode` fields, see `gameModes.json`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct GameMode(Inner);
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Inner {
+    Classic,
+    Odin,
+    Aram,
+    Tutorial,
+    Urf,
+    DoomBots,
+    OneForAll,
+    Ascension,
+    FirstBlood,
+    KingPoro,
+    NexusSiege,
+    Assassinate,
+    AllRandomSummonersRift,
+    DarkStar,
+    StarGuardian,
+    Project,
+    GameModeX,
+    Odyssey,
+    NexusBlitz,
+    UltimateSpellbook,
+    PracticeTool,
+    Arena,
+}
+
+const ALL: [GameMode; 22] = [
+    GameMode::CLASSIC,
+    GameMode::ODIN,
+    GameMode::ARAM,
+    GameMode::TUTORIAL,
+    GameMode::URF,
+    GameMode::DOOM_BOTS,
+    GameMode::ONE_FOR_ALL,
+    GameMode::ASCENSION,
+    GameMode::FIRST_BLOOD,
+    GameMode::KING_PORO,
+    GameMode::NEXUS_SIEGE,
+    GameMode::ASSASSINATE,
+    GameMode::ALL_RANDOM_SUMMONERS_RIFT,
+    GameMode::DARK_STAR,
+    GameMode::STAR_GUARDIAN,
+    GameMode::PROJECT,
+    GameMode::GAMEMODEX,
+    GameMode::ODYSSEY,
+    GameMode::NEXUS_BLITZ,
+    GameMode::ULTIMATE_SPELLBOOK,
+    GameMode::PRACTICE_TOOL,
+    GameMode::ARENA,
+];
+
+impl GameMode {
+    /// Classic Summoner's Rift and Twisted Treeline games
+    pub const CLASSIC: GameMode = GameMode(Classic);
+    /// Dominion/Crystal Scar games
+    pub const ODIN: GameMode = GameMode(Odin);
+    /// ARAM games
+    pub const ARAM: GameMode = GameMode(Aram);
+    /// Tutorial games
+    pub const TUTORIAL: GameMode = GameMode(Tutorial);
+    /// URF games
+    pub const URF: GameMode = GameMode(Urf);
+    /// Doom Bot games
+    pub const DOOM_BOTS: GameMode = GameMode(DoomBots);
+    /// One for All games
+    pub const ONE_FOR_ALL: GameMode = GameMode(OneForAll);
+    /// Ascension games
+    pub const ASCENSION: GameMode = GameMode(Ascension);
+    /// Snowdown Showdown games
+    pub const FIRST_BLOOD: GameMode = GameMode(FirstBlood);
+    /// Legend of the Poro King games
+    pub const KING_PORO: GameMode = GameMode(KingPoro);
+    /// Nexus Siege games
+    pub const NEXUS_SIEGE: GameMode = GameMode(NexusSiege);
+    /// Blood Hunt Assassin games
+    pub const ASSASSINATE: GameMode = GameMode(Assassinate);
+    /// All Random Summoner's Rift games
+    pub const ALL_RANDOM_SUMMONERS_RIFT: GameMode = GameMode(AllRandomSummonersRift);
+    /// Dark Star: Singularity games
+    pub const DARK_STAR: GameMode = GameMode(DarkStar);
+    /// Star Guardian Invasion games
+    pub const STAR_GUARDIAN: GameMode = GameMode(StarGuardian);
+    /// PROJECT: Hunters games
+    pub const PROJECT: GameMode = GameMode(Project);
+    /// Nexus Blitz games (test version)
+    pub const GAMEMODEX: GameMode = GameMode(GameModeX);
+    /// Odyssey: Extraction games
+    pub const ODYSSEY: GameMode = GameMode(Odyssey);
+    /// Nexus Blitz games
+    pub const NEXUS_BLITZ: GameMode = GameMode(NexusBlitz);
+    /// Ultimate Spellbook games
+    pub const ULTIMATE_SPELLBOOK: GameMode = GameMode(UltimateSpellbook);
+    /// Practice Tool games
+    pub const PRACTICE_TOOL: GameMode = GameMode(PracticeTool);
+    /// Arena games
+    pub const ARENA: GameMode = GameMode(Arena);
+
+    /// String representation of a game mode
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self.0 {
+            Classic => "CLASSIC",
+            Odin => "ODIN",
+            Aram => "ARAM",
+            Tutorial => "TUTORIAL",
+            Urf => "URF",
+            DoomBots => "DOOMBOTSTEEMO",
+            OneForAll => "ONEFORALL",
+            Ascension => "ASCENSION",
+            FirstBlood => "FIRSTBLOOD",
+            KingPoro => "KINGPORO",
+            NexusSiege => "SIEGE",
+            Assassinate => "ASSASSINATE",
+            AllRandomSummonersRift => "ARSR",
+            DarkStar => "DARKSTAR",
+            StarGuardian => "STARGUARDIAN",
+            Project => "PROJECT",
+            GameModeX => "GAMEMODEX",
+            Odyssey => "ODYSSEY",
+            NexusBlitz => "NEXUSBLITZ",
+            UltimateSpellbook => "ULTBOOK",
+            PracticeTool => "PRACTICETOOL",
+            Arena => "CHERRY",
+        }
+    }
+
+    /// Iterates over all game mode values
+    pub fn all() -> impl Iterator<Item = GameMode> {
+        ALL.iter().cloned()
+    }
+}
+
+impl AsRef<str> for GameMode {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for GameMode {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl PartialEq<&str> for GameMode {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_ref() == *other
+    }
+}
+
+impl PartialEq<GameMode> for str {
+    #[inline]
+    fn eq(&self, other: &GameMode) -> bool {
+        self == other.as_ref()
+    }
+}
+
+impl fmt::Debug for GameMode {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+impl fmt::Display for GameMode {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+impl FromStr for GameMode {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<GameMode, ClientError> {
+        GameMode::all()
+            .find(|value| value.as_str().eq_ignore_ascii_case(s))
+            .context(UnknownConstant {
+                kind: "game mode",
+                value: s,
+            })
+    }
+}
+
+impl Serialize for GameMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for GameMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<GameMode, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GameMode;
+
+    #[test]
+    fn game_mode_uses_riot_name() {
+        assert_eq!(GameMode::ONE_FOR_ALL, "ONEFORALL");
+        assert_eq!(GameMode::ARENA.to_string(), "CHERRY")
+    }
+
+    #[test]
+    fn game_mode_round_trips_through_serde() {
+        let mode: GameMode = serde_json::from_str("\"cherry\"").unwrap();
+        assert_eq!(mode, GameMode::ARENA);
+        assert_eq!(serde_json::to_string(&mode).unwrap(), "\"CHERRY\"");
+        assert!("ARENA".parse::<GameMode>().is_err());
+        assert_eq!(GameMode::all().count(), 22);
+    }
+}
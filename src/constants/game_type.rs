@@ -0,0 +1,136 @@
+use self::Inner::*;
+use crate::error::{ClientError, UnknownConstant};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use snafu::OptionExt;
+use std::convert::AsRef;
+use std::fmt;
+use std::str::FromStr;
+
+/// Game type as found in match `gameType` fields, see `gameTypes.json`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct GameType(Inner);
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Inner {
+    Custom,
+    Tutorial,
+    Matched,
+}
+
+const ALL: [GameType; 3] = [
+    GameType::CUSTOM_GAME,
+    GameType::TUTORIAL_GAME,
+    GameType::MATCHED_GAME,
+];
+
+impl GameType {
+    /// Custom games
+    pub const CUSTOM_GAME: GameType = GameType(Custom);
+    /// Tutorial games
+    pub const TUTORIAL_GAME: GameType = GameType(Tutorial);
+    /// All other games
+    pub const MATCHED_GAME: GameType = GameType(Matched);
+
+    /// String representation of a game type
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self.0 {
+            Custom => "CUSTOM_GAME",
+            Tutorial => "TUTORIAL_GAME",
+            Matched => "MATCHED_GAME",
+        }
+    }
+
+    /// Iterates over all game type values
+    pub fn all() -> impl Iterator<Item = GameType> {
+        ALL.iter().cloned()
+    }
+}
+
+impl AsRef<str> for GameType {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for GameType {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl PartialEq<&str> for GameType {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_ref() == *other
+    }
+}
+
+impl PartialEq<GameType> for str {
+    #[inline]
+    fn eq(&self, other: &GameType) -> bool {
+        self == other.as_ref()
+    }
+}
+
+impl fmt::Debug for GameType {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+impl fmt::Display for GameType {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+impl FromStr for GameType {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<GameType, ClientError> {
+        GameType::all()
+            .find(|value| value.as_str().eq_ignore_ascii_case(s))
+            .context(UnknownConstant {
+                kind: "game type",
+                value: s,
+            })
+    }
+}
+
+impl Serialize for GameType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for GameType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<GameType, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GameType;
+
+    #[test]
+    fn game_type_uses_riot_name() {
+        assert_eq!(GameType::MATCHED_GAME, "MATCHED_GAME")
+    }
+
+    #[test]
+    fn game_type_round_trips_through_serde() {
+        let game_type: GameType = serde_json::from_str("\"custom_game\"").unwrap();
+        assert_eq!(game_type, GameType::CUSTOM_GAME);
+        assert_eq!(
+            serde_json::to_string(&game_type).unwrap(),
+            "\"CUSTOM_GAME\""
+        );
+        assert_eq!(GameType::all().count(), 3);
+    }
+}
@@ -1,7 +1,11 @@
 use self::Inner::*;
-
+use crate::error::{ClientError, UnknownConstant};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use snafu::OptionExt;
 use std::convert::AsRef;
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct LanguageCode(Inner);
@@ -38,6 +42,37 @@ enum Inner {
     Taiwan,
 }
 
+const ALL: [LanguageCode; 28] = [
+    LanguageCode::CZECH_REPUBLIC,
+    LanguageCode::GREECE,
+    LanguageCode::POLAND,
+    LanguageCode::ROMANIA,
+    LanguageCode::HUNGARY,
+    LanguageCode::UNITED_KINGDOM,
+    LanguageCode::GERMANY,
+    LanguageCode::SPAIN,
+    LanguageCode::ITALY,
+    LanguageCode::FRANCE,
+    LanguageCode::JAPAN,
+    LanguageCode::KOREA,
+    LanguageCode::MEXICO,
+    LanguageCode::ARGENTINA,
+    LanguageCode::BRAZIL,
+    LanguageCode::UNITED_STATES,
+    LanguageCode::AUSTRALIA,
+    LanguageCode::RUSSIA,
+    LanguageCode::TURKEY,
+    LanguageCode::MALAYSIA,
+    LanguageCode::PHILIPINNES,
+    LanguageCode::SINGAPORE,
+    LanguageCode::THAILAND,
+    LanguageCode::VIETNAM,
+    LanguageCode::INDONESIA,
+    LanguageCode::MALAYSIA_CHINESE,
+    LanguageCode::CHINA,
+    LanguageCode::TAIWAN,
+];
+
 impl LanguageCode {
     /// Czech Republic language code
     pub const CZECH_REPUBLIC: LanguageCode = LanguageCode(CzechRepublic);
@@ -129,6 +164,11 @@ impl LanguageCode {
             Taiwan => "zh_TW",
         }
     }
+
+    /// Iterates over all language code values
+    pub fn all() -> impl Iterator<Item = LanguageCode> {
+        ALL.iter().cloned()
+    }
 }
 
 impl AsRef<str> for LanguageCode {
@@ -185,6 +225,32 @@ impl fmt::Display for LanguageCode {
     }
 }
 
+impl FromStr for LanguageCode {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<LanguageCode, ClientError> {
+        LanguageCode::all()
+            .find(|value| value.as_str().eq_ignore_ascii_case(s))
+            .context(UnknownConstant {
+                kind: "language code",
+                value: s,
+            })
+    }
+}
+
+impl Serialize for LanguageCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for LanguageCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<LanguageCode, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::LanguageCode;
@@ -193,4 +259,18 @@ mod tests {
     fn lang_code_returns_correct_lang_string() {
         assert_eq!(LanguageCode::TURKEY, "tr_TR")
     }
+
+    #[test]
+    fn parses_language_codes_ignoring_case() {
+        assert_eq!(
+            "en_gb".parse::<LanguageCode>().unwrap(),
+            LanguageCode::UNITED_KINGDOM
+        );
+        assert!("en-GB".parse::<LanguageCode>().is_err());
+        assert!(LanguageCode::all().all(|language| language
+            .as_str()
+            .parse::<LanguageCode>()
+            .unwrap()
+            == language));
+    }
 }
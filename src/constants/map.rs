@@ -0,0 +1,232 @@
+use self::Inner::*;
+use crate::error::{ClientError, UnknownConstant};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use snafu::OptionExt;
+use std::convert::AsRef;
+use std::fmt;
+use std::str::FromStr;
+
+/// Game map, see `maps.json`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Map(Inner);
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Inner {
+    CrystalScar,
+    TwistedTreeline,
+    SummonersRift,
+    HowlingAbyss,
+    ButchersBridge,
+    CosmicRuins,
+    ValoranCityPark,
+    Substructure43,
+    CrashSite,
+    NexusBlitz,
+    Convergence,
+    RingsOfWrath,
+}
+
+const ALL: [Map; 12] = [
+    Map::CRYSTAL_SCAR,
+    Map::TWISTED_TREELINE,
+    Map::SUMMONERS_RIFT,
+    Map::HOWLING_ABYSS,
+    Map::BUTCHERS_BRIDGE,
+    Map::COSMIC_RUINS,
+    Map::VALORAN_CITY_PARK,
+    Map::SUBSTRUCTURE_43,
+    Map::CRASH_SITE,
+    Map::NEXUS_BLITZ,
+    Map::CONVERGENCE,
+    Map::RINGS_OF_WRATH,
+];
+
+impl Map {
+    /// Dominion map
+    pub const CRYSTAL_SCAR: Map = Map(CrystalScar);
+    /// Current Twisted Treeline
+    pub const TWISTED_TREELINE: Map = Map(TwistedTreeline);
+    /// Current Summoner's Rift
+    pub const SUMMONERS_RIFT: Map = Map(SummonersRift);
+    /// ARAM map
+    pub const HOWLING_ABYSS: Map = Map(HowlingAbyss);
+    /// Alternate ARAM map
+    pub const BUTCHERS_BRIDGE: Map = Map(ButchersBridge);
+    /// Dark Star: Singularity map
+    pub const COSMIC_RUINS: Map = Map(CosmicRuins);
+    /// Star Guardian Invasion map
+    pub const VALORAN_CITY_PARK: Map = Map(ValoranCityPark);
+    /// PROJECT: Hunters map
+    pub const SUBSTRUCTURE_43: Map = Map(Substructure43);
+    /// Odyssey: Extraction map
+    pub const CRASH_SITE: Map = Map(CrashSite);
+    /// Nexus Blitz map
+    pub const NEXUS_BLITZ: Map = Map(NexusBlitz);
+    /// Teamfight Tactics map
+    pub const CONVERGENCE: Map = Map(Convergence);
+    /// Arena map
+    pub const RINGS_OF_WRATH: Map = Map(RingsOfWrath);
+
+    /// Map name
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self.0 {
+            CrystalScar => "The Crystal Scar",
+            TwistedTreeline => "Twisted Treeline",
+            SummonersRift => "Summoner's Rift",
+            HowlingAbyss => "Howling Abyss",
+            ButchersBridge => "Butcher's Bridge",
+            CosmicRuins => "Cosmic Ruins",
+            ValoranCityPark => "Valoran City Park",
+            Substructure43 => "Substructure 43",
+            CrashSite => "Crash Site",
+            NexusBlitz => "Nexus Blitz",
+            Convergence => "Convergence",
+            RingsOfWrath => "Rings of Wrath",
+        }
+    }
+
+    /// Numeric map id as found in match `mapId` fields
+    #[inline]
+    pub fn id(&self) -> i64 {
+        match self.0 {
+            CrystalScar => 8,
+            TwistedTreeline => 10,
+            SummonersRift => 11,
+            HowlingAbyss => 12,
+            ButchersBridge => 14,
+            CosmicRuins => 16,
+            ValoranCityPark => 18,
+            Substructure43 => 19,
+            CrashSite => 20,
+            NexusBlitz => 21,
+            Convergence => 22,
+            RingsOfWrath => 30,
+        }
+    }
+
+    /// Map for a numeric `mapId`
+    pub fn from_id(id: i64) -> Option<Map> {
+        let inner = match id {
+            8 => CrystalScar,
+            10 => TwistedTreeline,
+            11 => SummonersRift,
+            12 => HowlingAbyss,
+            14 => ButchersBridge,
+            16 => CosmicRuins,
+            18 => ValoranCityPark,
+            19 => Substructure43,
+            20 => CrashSite,
+            21 => NexusBlitz,
+            22 => Convergence,
+            30 => RingsOfWrath,
+            _ => return None,
+        };
+        Some(Map(inner))
+    }
+
+    /// Iterates over all map values
+    pub fn all() -> impl Iterator<Item = Map> {
+        ALL.iter().cloned()
+    }
+}
+
+impl AsRef<str> for Map {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for Map {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl PartialEq<&str> for Map {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_ref() == *other
+    }
+}
+
+impl PartialEq<Map> for str {
+    #[inline]
+    fn eq(&self, other: &Map) -> bool {
+        self == other.as_ref()
+    }
+}
+
+impl fmt::Debug for Map {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+/// Parses map names such as `Summoner's Rift`, ignoring case.
+impl FromStr for Map {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<Map, ClientError> {
+        Map::all()
+            .find(|value| value.as_str().eq_ignore_ascii_case(s))
+            .context(UnknownConstant {
+                kind: "map",
+                value: s,
+            })
+    }
+}
+
+/// Serialized as the numeric `mapId` used by match data.
+impl Serialize for Map {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for Map {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Map, D::Error> {
+        let id = i64::deserialize(deserializer)?;
+        Map::from_id(id)
+            .context(UnknownConstant {
+                kind: "map",
+                value: id.to_string(),
+            })
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+
+    #[test]
+    fn converts_map_ids() {
+        assert_eq!(Map::from_id(12), Some(Map::HOWLING_ABYSS));
+        assert_eq!(Map::SUMMONERS_RIFT.id(), 11);
+        assert_eq!(Map::SUMMONERS_RIFT, "Summoner's Rift");
+        assert_eq!(Map::from_id(-1), None)
+    }
+
+    #[test]
+    fn map_round_trips_through_serde() {
+        let map: Map = serde_json::from_str("12").unwrap();
+        assert_eq!(map, Map::HOWLING_ABYSS);
+        assert_eq!(serde_json::to_string(&map).unwrap(), "12");
+        assert!(serde_json::from_str::<Map>("-1").is_err());
+        assert_eq!(
+            "summoner's rift".parse::<Map>().unwrap(),
+            Map::SUMMONERS_RIFT
+        );
+        assert_eq!(Map::all().count(), 12);
+    }
+}
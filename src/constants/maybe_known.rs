@@ -0,0 +1,103 @@
+use crate::error::ClientError;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// A constant parsed from api data, keeping the raw value if it is not known yet.
+///
+/// Riot adds tiers and queues from time to time; deserializing them into `MaybeKnown` keeps
+/// responses usable and serializes back to the exact value that was received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaybeKnown<T> {
+    Known(T),
+    Unknown(String),
+}
+
+impl<T> MaybeKnown<T> {
+    /// The parsed constant, `None` for unknown values
+    pub fn known(&self) -> Option<&T> {
+        match self {
+            MaybeKnown::Known(value) => Some(value),
+            MaybeKnown::Unknown(_) => None,
+        }
+    }
+
+    #[inline]
+    pub fn is_known(&self) -> bool {
+        self.known().is_some()
+    }
+}
+
+impl<T: Clone + FromStr<Err = ClientError>> MaybeKnown<T> {
+    /// The parsed constant, or the parse error of the raw value
+    pub fn try_known(&self) -> Result<T, ClientError> {
+        match self {
+            MaybeKnown::Known(value) => Ok(value.clone()),
+            MaybeKnown::Unknown(raw) => raw.parse(),
+        }
+    }
+}
+
+impl<T: AsRef<str>> MaybeKnown<T> {
+    /// The value as received from the api
+    pub fn as_str(&self) -> &str {
+        match self {
+            MaybeKnown::Known(value) => value.as_ref(),
+            MaybeKnown::Unknown(raw) => raw,
+        }
+    }
+}
+
+impl<T> From<T> for MaybeKnown<T> {
+    #[inline]
+    fn from(value: T) -> MaybeKnown<T> {
+        MaybeKnown::Known(value)
+    }
+}
+
+impl<T: PartialEq> PartialEq<T> for MaybeKnown<T> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.known() == Some(other)
+    }
+}
+
+impl<T: AsRef<str>> fmt::Display for MaybeKnown<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+impl<T: AsRef<str>> Serialize for MaybeKnown<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de, T: FromStr> Deserialize<'de> for MaybeKnown<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<MaybeKnown<T>, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        Ok(match raw.parse() {
+            Ok(value) => MaybeKnown::Known(value),
+            Err(_) => MaybeKnown::Unknown(raw),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MaybeKnown;
+    use crate::constants::RankedTier;
+
+    #[test]
+    fn keeps_unknown_values() {
+        let tier: MaybeKnown<RankedTier> = serde_json::from_str("\"GOLD\"").unwrap();
+        assert_eq!(tier, RankedTier::GOLD);
+
+        let tier: MaybeKnown<RankedTier> = serde_json::from_str("\"EMERALD\"").unwrap();
+        assert_eq!(tier, MaybeKnown::Unknown("EMERALD".to_owned()));
+        assert!(tier.try_known().is_err());
+        assert_eq!(serde_json::to_string(&tier).unwrap(), "\"EMERALD\"");
+    }
+}
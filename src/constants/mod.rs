@@ -1,10 +1,31 @@
+pub mod champion;
+pub mod champion_tag;
 pub mod division;
+pub mod game_mode;
+pub mod game_type;
 pub mod lang_code;
+pub mod map;
+pub mod maybe_known;
+pub mod queue;
+pub mod rank;
 pub mod ranked_queue;
 pub mod ranked_tier;
 pub mod region;
+pub mod regional_route;
+pub mod resource_type;
 
+pub use champion::{Champion, KnownChampion, UnknownChampion};
+pub use champion_tag::ChampionTag;
+pub use division::Division;
+pub use game_mode::GameMode;
+pub use game_type::GameType;
 pub use lang_code::LanguageCode;
+pub use map::Map;
+pub use maybe_known::MaybeKnown;
+pub use queue::Queue;
+pub use rank::Rank;
 pub use ranked_queue::RankedQueue;
 pub use ranked_tier::RankedTier;
 pub use region::Region;
+pub use regional_route::RegionalRoute;
+pub use resource_type::ResourceType;
@@ -0,0 +1,268 @@
+use self::Inner::*;
+use crate::constants::{Map, RankedQueue};
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+
+/// Game queue keyed by the numeric `queueId` of match data, see `queues.json`.
+///
+/// Ids without a constant are kept as is, so newly added game modes still deserialize.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Queue(Inner);
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Inner {
+    Custom,
+    NormalDraft,
+    RankedSolo,
+    NormalBlind,
+    RankedFlex,
+    Aram,
+    RankedFlexTT,
+    Quickplay,
+    Clash,
+    AramClash,
+    CoopVsAiIntro,
+    CoopVsAiBeginner,
+    CoopVsAiIntermediate,
+    Arurf,
+    OneForAll,
+    NexusBlitz,
+    UltimateSpellbook,
+    Arena,
+    Urf,
+    Unknown(i64),
+}
+
+const KNOWN: [Queue; 19] = [
+    Queue::CUSTOM,
+    Queue::NORMAL_DRAFT,
+    Queue::RANKED_SOLO,
+    Queue::NORMAL_BLIND,
+    Queue::RANKED_FLEX,
+    Queue::ARAM,
+    Queue::RANKED_FLEX_TT,
+    Queue::QUICKPLAY,
+    Queue::CLASH,
+    Queue::ARAM_CLASH,
+    Queue::COOP_VS_AI_INTRO,
+    Queue::COOP_VS_AI_BEGINNER,
+    Queue::COOP_VS_AI_INTERMEDIATE,
+    Queue::ARURF,
+    Queue::ONE_FOR_ALL,
+    Queue::NEXUS_BLITZ,
+    Queue::ULTIMATE_SPELLBOOK,
+    Queue::ARENA,
+    Queue::URF,
+];
+
+impl Queue {
+    /// Custom games
+    pub const CUSTOM: Queue = Queue(Custom);
+    /// 5v5 Draft Pick games
+    pub const NORMAL_DRAFT: Queue = Queue(NormalDraft);
+    /// 5v5 Ranked Solo games
+    pub const RANKED_SOLO: Queue = Queue(RankedSolo);
+    /// 5v5 Blind Pick games
+    pub const NORMAL_BLIND: Queue = Queue(NormalBlind);
+    /// 5v5 Ranked Flex games
+    pub const RANKED_FLEX: Queue = Queue(RankedFlex);
+    /// 5v5 ARAM games
+    pub const ARAM: Queue = Queue(Aram);
+    /// 3v3 Ranked Flex games
+    pub const RANKED_FLEX_TT: Queue = Queue(RankedFlexTT);
+    /// Quickplay games
+    pub const QUICKPLAY: Queue = Queue(Quickplay);
+    /// Summoner's Rift Clash games
+    pub const CLASH: Queue = Queue(Clash);
+    /// ARAM Clash games
+    pub const ARAM_CLASH: Queue = Queue(AramClash);
+    /// Co-op vs. AI Intro Bot games
+    pub const COOP_VS_AI_INTRO: Queue = Queue(CoopVsAiIntro);
+    /// Co-op vs. AI Beginner Bot games
+    pub const COOP_VS_AI_BEGINNER: Queue = Queue(CoopVsAiBeginner);
+    /// Co-op vs. AI Intermediate Bot games
+    pub const COOP_VS_AI_INTERMEDIATE: Queue = Queue(CoopVsAiIntermediate);
+    /// ARURF games
+    pub const ARURF: Queue = Queue(Arurf);
+    /// One for All games
+    pub const ONE_FOR_ALL: Queue = Queue(OneForAll);
+    /// Nexus Blitz games
+    pub const NEXUS_BLITZ: Queue = Queue(NexusBlitz);
+    /// Ultimate Spellbook games
+    pub const ULTIMATE_SPELLBOOK: Queue = Queue(UltimateSpellbook);
+    /// Arena games
+    pub const ARENA: Queue = Queue(Arena);
+    /// Pick URF games
+    pub const URF: Queue = Queue(Urf);
+
+    /// Queue for a numeric `queueId`, ids without a constant are kept as unknown queues
+    pub fn from_id(id: i64) -> Queue {
+        KNOWN
+            .iter()
+            .find(|queue| queue.id() == id)
+            .cloned()
+            .unwrap_or(Queue(Unknown(id)))
+    }
+
+    /// Numeric `queueId`
+    #[inline]
+    pub fn id(&self) -> i64 {
+        match self.0 {
+            Custom => 0,
+            NormalDraft => 400,
+            RankedSolo => 420,
+            NormalBlind => 430,
+            RankedFlex => 440,
+            Aram => 450,
+            RankedFlexTT => 470,
+            Quickplay => 490,
+            Clash => 700,
+            AramClash => 720,
+            CoopVsAiIntro => 830,
+            CoopVsAiBeginner => 840,
+            CoopVsAiIntermediate => 850,
+            Arurf => 900,
+            OneForAll => 1020,
+            NexusBlitz => 1300,
+            UltimateSpellbook => 1400,
+            Arena => 1700,
+            Urf => 1900,
+            Unknown(id) => id,
+        }
+    }
+
+    /// Whether the queue has a constant in this crate
+    #[inline]
+    pub fn is_known(&self) -> bool {
+        !matches!(self.0, Unknown(_))
+    }
+
+    /// Description from `queues.json`, `None` for unknown queues
+    pub fn description(&self) -> Option<&str> {
+        let description = match self.0 {
+            Custom => "Custom games",
+            NormalDraft => "5v5 Draft Pick games",
+            RankedSolo => "5v5 Ranked Solo games",
+            NormalBlind => "5v5 Blind Pick games",
+            RankedFlex => "5v5 Ranked Flex games",
+            Aram => "5v5 ARAM games",
+            RankedFlexTT => "3v3 Ranked Flex games",
+            Quickplay => "Quickplay games",
+            Clash => "Summoner's Rift Clash games",
+            AramClash => "ARAM Clash games",
+            CoopVsAiIntro => "Co-op vs. AI Intro Bot games",
+            CoopVsAiBeginner => "Co-op vs. AI Beginner Bot games",
+            CoopVsAiIntermediate => "Co-op vs. AI Intermediate Bot games",
+            Arurf => "ARURF games",
+            OneForAll => "One for All games",
+            NexusBlitz => "Nexus Blitz games",
+            UltimateSpellbook => "Ultimate Spellbook games",
+            Arena => "Arena games",
+            Urf => "Pick URF games",
+            Unknown(_) => return None,
+        };
+        Some(description)
+    }
+
+    /// Map the queue is played on, `None` for custom games and unknown queues
+    pub fn map(&self) -> Option<Map> {
+        let map = match self.0 {
+            NormalDraft => Map::SUMMONERS_RIFT,
+            RankedSolo => Map::SUMMONERS_RIFT,
+            NormalBlind => Map::SUMMONERS_RIFT,
+            RankedFlex => Map::SUMMONERS_RIFT,
+            Aram => Map::HOWLING_ABYSS,
+            RankedFlexTT => Map::TWISTED_TREELINE,
+            Quickplay => Map::SUMMONERS_RIFT,
+            Clash => Map::SUMMONERS_RIFT,
+            AramClash => Map::HOWLING_ABYSS,
+            CoopVsAiIntro => Map::SUMMONERS_RIFT,
+            CoopVsAiBeginner => Map::SUMMONERS_RIFT,
+            CoopVsAiIntermediate => Map::SUMMONERS_RIFT,
+            Arurf => Map::SUMMONERS_RIFT,
+            OneForAll => Map::SUMMONERS_RIFT,
+            NexusBlitz => Map::NEXUS_BLITZ,
+            UltimateSpellbook => Map::SUMMONERS_RIFT,
+            Arena => Map::RINGS_OF_WRATH,
+            Urf => Map::SUMMONERS_RIFT,
+            Custom | Unknown(_) => return None,
+        };
+        Some(map)
+    }
+
+    /// Ranked queue this queue belongs to, if any
+    pub fn ranked_queue(&self) -> Option<RankedQueue> {
+        RankedQueue::from_queue_id(self.id())
+    }
+
+    /// Iterates over all queues that have a constant
+    pub fn all() -> impl Iterator<Item = Queue> {
+        KNOWN.iter().cloned()
+    }
+}
+
+impl From<RankedQueue> for Queue {
+    fn from(queue: RankedQueue) -> Queue {
+        Queue::from_id(queue.queue_id())
+    }
+}
+
+impl From<i64> for Queue {
+    fn from(id: i64) -> Queue {
+        Queue::from_id(id)
+    }
+}
+
+impl Serialize for Queue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for Queue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Queue, D::Error> {
+        i64::deserialize(deserializer).map(Queue::from_id)
+    }
+}
+
+impl fmt::Debug for Queue {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Queue({})", self.id())
+    }
+}
+
+/// Formats the queue description, or `Queue <id>` for unknown queues.
+impl fmt::Display for Queue {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.description() {
+            Some(description) => fmt.write_str(description),
+            None => write!(fmt, "Queue {}", self.id()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Queue;
+    use crate::constants::{Map, RankedQueue};
+
+    #[test]
+    fn converts_queue_ids() {
+        assert_eq!(Queue::from_id(450), Queue::ARAM);
+        assert_eq!(Queue::ARAM.map(), Some(Map::HOWLING_ABYSS));
+        assert_eq!(Queue::RANKED_SOLO.ranked_queue(), Some(RankedQueue::SOLO));
+        assert_eq!(Queue::from(RankedQueue::FLEX), Queue::RANKED_FLEX);
+        assert_eq!(Queue::CLASH.ranked_queue(), None);
+    }
+
+    #[test]
+    fn keeps_unknown_queue_ids() {
+        let queue: Queue = serde_json::from_str("4242").unwrap();
+        assert!(!queue.is_known());
+        assert_eq!(queue.id(), 4242);
+        assert_eq!(queue.to_string(), "Queue 4242");
+        assert_eq!(serde_json::to_string(&queue).unwrap(), "4242");
+        assert_eq!(Queue::URF.to_string(), "Pick URF games");
+    }
+}
@@ -0,0 +1,225 @@
+use crate::constants::{Division, RankedTier};
+use crate::dto::api::LeagueInfo;
+use crate::error::ClientError;
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// League points between two divisions.
+const DIVISION_LP: i64 = 100;
+/// League points between two tiers below master.
+const TIER_LP: i64 = 4 * DIVISION_LP;
+
+/// A ranked position: tier, division and league points.
+///
+/// Ranks are ordered by tier, then division, then LP, so `Iron IV 0 LP` is the lowest rank.
+/// Apex tiers (`MASTER` and above) have no divisions and always use [`Division::I`].
+///
+/// Below master a higher rank never has a lower [`ladder_score`]. Apex tiers share one LP ladder
+/// though, so `Master 500 LP` orders below `Grandmaster 200 LP` but has the higher ladder score.
+///
+/// [`Division::I`]: ../division/struct.Division.html#associatedconstant.I
+/// [`ladder_score`]: #method.ladder_score
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rank {
+    tier: RankedTier,
+    division: Division,
+    lp: i64,
+}
+
+impl Rank {
+    /// Creates a rank, using [`Division::I`] for apex tiers regardless of `division`.
+    ///
+    /// LP is clamped to `0..=100` below master, where 100 LP means a promotion series is running,
+    /// and to at least 0 for apex tiers.
+    ///
+    /// [`Division::I`]: ../division/struct.Division.html#associatedconstant.I
+    pub fn new(tier: RankedTier, division: Division, lp: i64) -> Rank {
+        if tier.is_apex() {
+            Rank {
+                tier,
+                division: Division::I,
+                lp: lp.max(0),
+            }
+        } else {
+            Rank {
+                tier,
+                division,
+                lp: lp.clamp(0, DIVISION_LP),
+            }
+        }
+    }
+
+    pub fn tier(&self) -> &RankedTier {
+        &self.tier
+    }
+
+    /// Division, always [`Division::I`] for apex tiers
+    ///
+    /// [`Division::I`]: ../division/struct.Division.html#associatedconstant.I
+    pub fn division(&self) -> &Division {
+        &self.division
+    }
+
+    pub fn lp(&self) -> i64 {
+        self.lp
+    }
+
+    /// Linear score of the rank, `0` at `Iron IV 0 LP`.
+    ///
+    /// Every division is worth 100 LP. The 100 LP reached before a promotion series still counts
+    /// as 99, so a division never scores as high as the next one. Apex tiers share one LP ladder
+    /// starting right above `Diamond I 99 LP`, so a Grandmaster's score only depends on their LP.
+    pub fn ladder_score(&self) -> i64 {
+        let master = tier_index(&RankedTier::MASTER) * TIER_LP;
+        if self.tier.is_apex() {
+            master + self.lp
+        } else {
+            tier_index(&self.tier) * TIER_LP
+                + i64::from(4 - self.division.number()) * DIVISION_LP
+                + self.lp.min(DIVISION_LP - 1)
+        }
+    }
+
+    /// Rank for a [`ladder_score`], the inverse of it below master except for promotion LP.
+    ///
+    /// Apex cutoffs change daily, so scores in the apex range resolve to `MASTER`.
+    ///
+    /// [`ladder_score`]: #method.ladder_score
+    pub fn from_ladder_score(score: i64) -> Rank {
+        let score = score.max(0);
+        let master = tier_index(&RankedTier::MASTER) * TIER_LP;
+        if score >= master {
+            return Rank::new(RankedTier::MASTER, Division::I, score - master);
+        }
+        let tier = RankedTier::all().nth((score / TIER_LP) as usize).unwrap();
+        let division = Division::all()
+            .nth((3 - (score % TIER_LP) / DIVISION_LP) as usize)
+            .unwrap();
+        Rank::new(tier, division, score % DIVISION_LP)
+    }
+}
+
+fn tier_index(tier: &RankedTier) -> i64 {
+    RankedTier::all().position(|t| t == *tier).unwrap() as i64
+}
+
+impl PartialOrd for Rank {
+    fn partial_cmp(&self, other: &Rank) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rank {
+    fn cmp(&self, other: &Rank) -> Ordering {
+        self.tier
+            .cmp(&other.tier)
+            .then_with(|| self.division.cmp(&other.division))
+            .then_with(|| self.lp.cmp(&other.lp))
+    }
+}
+
+impl TryFrom<&LeagueInfo> for Rank {
+    type Error = ClientError;
+
+    fn try_from(info: &LeagueInfo) -> Result<Rank, ClientError> {
+        Ok(Rank::new(
+            info.tier.try_known()?,
+            info.rank.try_known()?,
+            info.league_points,
+        ))
+    }
+}
+
+/// Formats ranks like `Gold II 45 LP`, or `Challenger 1024 LP` for apex tiers.
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.tier.is_apex() {
+            write!(f, "{} {} LP", self.tier.name(), self.lp)
+        } else {
+            write!(f, "{} {} {} LP", self.tier.name(), self.division, self.lp)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rank;
+    use crate::constants::{Division, RankedTier};
+    use crate::dto::api::LeagueInfo;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn orders_and_formats_ranks() {
+        let gold = Rank::new(RankedTier::GOLD, Division::II, 45);
+        let better_gold = Rank::new(RankedTier::GOLD, Division::I, 0);
+        let challenger = Rank::new(RankedTier::CHALLENGER, Division::IV, 1024);
+        assert!(gold < better_gold);
+        assert!(better_gold < challenger);
+        assert!(Rank::new(RankedTier::IRON, Division::IV, 0) < gold);
+        assert_eq!(gold.to_string(), "Gold II 45 LP");
+        assert_eq!(challenger.to_string(), "Challenger 1024 LP");
+    }
+
+    #[test]
+    fn parses_league_info() {
+        let info: LeagueInfo = serde_json::from_str(
+            r#"{"queueType":"RANKED_SOLO_5x5","summonerName":"Vetro","hotStreak":false,
+                "wins":10,"veteran":false,"losses":8,"rank":"II","tier":"GOLD","inactive":false,
+                "freshBlood":true,"leagueId":"id","summonerId":"id","leaguePoints":45}"#,
+        )
+        .unwrap();
+        let rank = Rank::try_from(&info).unwrap();
+        assert_eq!(rank, Rank::new(RankedTier::GOLD, Division::II, 45));
+    }
+
+    #[test]
+    fn converts_ladder_scores() {
+        let gold = Rank::new(RankedTier::GOLD, Division::II, 45);
+        assert_eq!(gold.ladder_score(), 3 * 400 + 2 * 100 + 45);
+        assert_eq!(Rank::from_ladder_score(gold.ladder_score()), gold);
+        assert_eq!(
+            Rank::from_ladder_score(0),
+            Rank::new(RankedTier::IRON, Division::IV, 0)
+        );
+        let master = Rank::new(RankedTier::MASTER, Division::I, 120);
+        assert_eq!(Rank::from_ladder_score(master.ladder_score()), master);
+    }
+
+    #[test]
+    fn promotion_lp_stays_below_the_next_division() {
+        let promo = Rank::new(RankedTier::GOLD, Division::II, 100);
+        let next = Rank::new(RankedTier::GOLD, Division::I, 0);
+        assert!(promo < next);
+        assert!(promo.ladder_score() < next.ladder_score());
+
+        let diamond_promo = Rank::new(RankedTier::DIAMOND, Division::I, 100);
+        let master = Rank::new(RankedTier::MASTER, Division::I, 0);
+        assert!(diamond_promo < master);
+        assert!(diamond_promo.ladder_score() < master.ladder_score());
+    }
+
+    #[test]
+    fn normalizes_division_and_lp() {
+        let challenger = Rank::new(RankedTier::CHALLENGER, Division::IV, -5);
+        assert_eq!(challenger.division(), &Division::I);
+        assert_eq!(challenger.lp(), 0);
+
+        let promo = Rank::new(RankedTier::GOLD, Division::II, 250);
+        assert_eq!(promo.lp(), 100);
+        assert_eq!(promo, Rank::new(RankedTier::GOLD, Division::II, 100));
+        let almost = Rank::new(RankedTier::GOLD, Division::II, 99);
+        assert!(almost < promo);
+        assert!(almost.ladder_score() <= promo.ladder_score());
+        assert_eq!(Rank::new(RankedTier::GOLD, Division::II, -20).lp(), 0);
+    }
+
+    #[test]
+    fn apex_tiers_order_by_tier_but_score_by_lp() {
+        let master = Rank::new(RankedTier::MASTER, Division::I, 500);
+        let grandmaster = Rank::new(RankedTier::GRANDMASTER, Division::I, 200);
+        assert!(master < grandmaster);
+        assert!(master.ladder_score() > grandmaster.ladder_score());
+    }
+}
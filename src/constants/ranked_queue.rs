@@ -1,6 +1,11 @@
 use self::Inner::*;
+use crate::error::{ClientError, UnknownConstant};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use snafu::OptionExt;
 use std::convert::AsRef;
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct RankedQueue(Inner);
@@ -12,6 +17,12 @@ enum Inner {
     TwistedTreeline,
 }
 
+const ALL: [RankedQueue; 3] = [
+    RankedQueue::SOLO,
+    RankedQueue::FLEX,
+    RankedQueue::TWISTED_TREELINE,
+];
+
 impl RankedQueue {
     /// Solo ranked queue
     pub const SOLO: RankedQueue = RankedQueue(Solo);
@@ -30,6 +41,31 @@ impl RankedQueue {
             TwistedTreeline => "RANKED_FLEX_TT",
         }
     }
+
+    /// Numeric queue id as found in match `queueId` fields
+    #[inline]
+    pub fn queue_id(&self) -> i64 {
+        match self.0 {
+            Solo => 420,
+            Flex => 440,
+            TwistedTreeline => 470,
+        }
+    }
+
+    /// Ranked queue for a match `queueId`, `None` for unranked queues
+    pub fn from_queue_id(queue_id: i64) -> Option<RankedQueue> {
+        match queue_id {
+            420 => Some(RankedQueue::SOLO),
+            440 => Some(RankedQueue::FLEX),
+            470 => Some(RankedQueue::TWISTED_TREELINE),
+            _ => None,
+        }
+    }
+
+    /// Iterates over all ranked queue values
+    pub fn all() -> impl Iterator<Item = RankedQueue> {
+        ALL.iter().cloned()
+    }
 }
 
 impl AsRef<str> for RankedQueue {
@@ -86,6 +122,32 @@ impl fmt::Display for RankedQueue {
     }
 }
 
+impl FromStr for RankedQueue {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<RankedQueue, ClientError> {
+        RankedQueue::all()
+            .find(|value| value.as_str().eq_ignore_ascii_case(s))
+            .context(UnknownConstant {
+                kind: "ranked queue",
+                value: s,
+            })
+    }
+}
+
+impl Serialize for RankedQueue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RankedQueue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RankedQueue, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::RankedQueue;
@@ -100,4 +162,20 @@ mod tests {
         let five_x_five = RankedQueue::SOLO;
         assert_eq!(&five_x_five, "RANKED_SOLO_5x5")
     }
+
+    #[test]
+    fn converts_queue_ids() {
+        assert_eq!(RankedQueue::from_queue_id(420), Some(RankedQueue::SOLO));
+        assert_eq!(RankedQueue::from_queue_id(440), Some(RankedQueue::FLEX));
+        assert_eq!(RankedQueue::from_queue_id(450), None);
+        assert_eq!(RankedQueue::FLEX.queue_id(), 440)
+    }
+
+    #[test]
+    fn ranked_queue_round_trips_through_serde() {
+        let queue: RankedQueue = serde_json::from_str("\"ranked_flex_sr\"").unwrap();
+        assert_eq!(queue, RankedQueue::FLEX);
+        assert_eq!(serde_json::to_string(&queue).unwrap(), "\"RANKED_FLEX_SR\"");
+        assert_eq!(RankedQueue::all().count(), 3);
+    }
 }
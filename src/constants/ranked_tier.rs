@@ -1,11 +1,17 @@
+use crate::error::{ClientError, UnknownConstant};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use snafu::OptionExt;
 use std::convert::AsRef;
 use std::fmt;
+use std::str::FromStr;
 use Inner::*;
 
-#[derive(Clone, PartialEq, Eq)]
+/// Ranked tiers compare in ladder order, from `IRON` to `CHALLENGER`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct RankedTier(Inner);
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 enum Inner {
     Iron,
     Bronze,
@@ -18,6 +24,18 @@ enum Inner {
     Challenger,
 }
 
+const ALL: [RankedTier; 9] = [
+    RankedTier::IRON,
+    RankedTier::BRONZE,
+    RankedTier::SILVER,
+    RankedTier::GOLD,
+    RankedTier::PLATINUM,
+    RankedTier::DIAMOND,
+    RankedTier::MASTER,
+    RankedTier::GRANDMASTER,
+    RankedTier::CHALLENGER,
+];
+
 impl RankedTier {
     pub const IRON: RankedTier = RankedTier(Iron);
 
@@ -51,6 +69,33 @@ impl RankedTier {
             Challenger => "CHALLENGER",
         }
     }
+
+    /// Capitalized tier name, e.g. `Gold`
+    #[inline]
+    pub fn name(&self) -> &str {
+        match self.0 {
+            Iron => "Iron",
+            Bronze => "Bronze",
+            Silver => "Silver",
+            Gold => "Gold",
+            Platinum => "Platinum",
+            Diamond => "Diamond",
+            Master => "Master",
+            Grandmaster => "Grandmaster",
+            Challenger => "Challenger",
+        }
+    }
+
+    /// Whether the tier is `MASTER` or above, which have no divisions
+    #[inline]
+    pub fn is_apex(&self) -> bool {
+        self >= &RankedTier::MASTER
+    }
+
+    /// Iterates over all ranked tier values
+    pub fn all() -> impl Iterator<Item = RankedTier> {
+        ALL.iter().cloned()
+    }
 }
 
 impl AsRef<str> for RankedTier {
@@ -105,3 +150,49 @@ impl fmt::Display for RankedTier {
         fmt.write_str(self.as_ref())
     }
 }
+
+impl FromStr for RankedTier {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<RankedTier, ClientError> {
+        RankedTier::all()
+            .find(|value| value.as_str().eq_ignore_ascii_case(s))
+            .context(UnknownConstant {
+                kind: "ranked tier",
+                value: s,
+            })
+    }
+}
+
+impl Serialize for RankedTier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RankedTier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RankedTier, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RankedTier;
+
+    #[test]
+    fn tiers_compare_in_ladder_order() {
+        assert!(RankedTier::IRON < RankedTier::BRONZE);
+        assert!(RankedTier::GRANDMASTER < RankedTier::CHALLENGER);
+        assert!(RankedTier::MASTER.is_apex());
+        assert!(!RankedTier::DIAMOND.is_apex());
+    }
+
+    #[test]
+    fn parses_ranked_tiers() {
+        assert_eq!("gold".parse::<RankedTier>().unwrap(), RankedTier::GOLD);
+        assert!("wood".parse::<RankedTier>().is_err());
+        assert_eq!(RankedTier::all().next(), Some(RankedTier::IRON));
+    }
+}
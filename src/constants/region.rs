@@ -1,6 +1,11 @@
 use self::Inner::*;
-
+use crate::constants::{LanguageCode, RegionalRoute};
+use crate::error::{ClientError, UnknownConstant};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use snafu::OptionExt;
 use std::convert::AsRef;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Region(Inner);
@@ -19,8 +24,35 @@ enum Inner {
     TR,
     RU,
     PBE,
+    PH,
+    SG,
+    TH,
+    TW,
+    VN,
+    ME,
 }
 
+const ALL: [Region; 18] = [
+    Region::BR,
+    Region::EUNE,
+    Region::EUW,
+    Region::JP,
+    Region::KR,
+    Region::LAN,
+    Region::LAS,
+    Region::NA,
+    Region::OCE,
+    Region::TR,
+    Region::RU,
+    Region::PBE,
+    Region::PH,
+    Region::SG,
+    Region::TH,
+    Region::TW,
+    Region::VN,
+    Region::ME,
+];
+
 impl Region {
     /// Brazil
     pub const BR: Region = Region(BR);
@@ -46,6 +78,18 @@ impl Region {
     pub const RU: Region = Region(RU);
     /// Public Beta Environment
     pub const PBE: Region = Region(PBE);
+    /// Philippines
+    pub const PH: Region = Region(PH);
+    /// Singapore
+    pub const SG: Region = Region(SG);
+    /// Thailand
+    pub const TH: Region = Region(TH);
+    /// Taiwan
+    pub const TW: Region = Region(TW);
+    /// Vietnam
+    pub const VN: Region = Region(VN);
+    /// Middle East
+    pub const ME: Region = Region(ME);
 
     /// String representation of Region
     #[inline]
@@ -63,6 +107,12 @@ impl Region {
             TR => "TR",
             RU => "RU",
             PBE => "PBE",
+            PH => "PH",
+            SG => "SG",
+            TH => "TH",
+            TW => "TW",
+            VN => "VN",
+            ME => "ME",
         }
     }
 
@@ -82,8 +132,88 @@ impl Region {
             TR => "TR1",
             RU => "RU",
             PBE => "PBE1",
+            PH => "PH2",
+            SG => "SG2",
+            TH => "TH2",
+            TW => "TW2",
+            VN => "VN2",
+            ME => "ME1",
+        }
+    }
+
+    /// Regional route serving this platform's match and tournament data
+    ///
+    /// Account data is not served by [`RegionalRoute::SEA`], see [`account_route`].
+    ///
+    /// [`RegionalRoute::SEA`]: ../regional_route/struct.RegionalRoute.html#associatedconstant.SEA
+    /// [`account_route`]: #method.account_route
+    #[inline]
+    pub fn route(&self) -> RegionalRoute {
+        match self.0 {
+            BR => RegionalRoute::AMERICAS,
+            EUNE => RegionalRoute::EUROPE,
+            EUW => RegionalRoute::EUROPE,
+            JP => RegionalRoute::ASIA,
+            KR => RegionalRoute::ASIA,
+            LAN => RegionalRoute::AMERICAS,
+            LAS => RegionalRoute::AMERICAS,
+            NA => RegionalRoute::AMERICAS,
+            OCE => RegionalRoute::SEA,
+            TR => RegionalRoute::EUROPE,
+            RU => RegionalRoute::EUROPE,
+            PBE => RegionalRoute::AMERICAS,
+            PH => RegionalRoute::SEA,
+            SG => RegionalRoute::SEA,
+            TH => RegionalRoute::SEA,
+            TW => RegionalRoute::SEA,
+            VN => RegionalRoute::SEA,
+            ME => RegionalRoute::EUROPE,
         }
     }
+
+    /// Regional route serving this platform's account data
+    ///
+    /// Same as [`route`], except that South East Asian platforms use [`RegionalRoute::ASIA`].
+    ///
+    /// [`route`]: #method.route
+    /// [`RegionalRoute::ASIA`]: ../regional_route/struct.RegionalRoute.html#associatedconstant.ASIA
+    #[inline]
+    pub fn account_route(&self) -> RegionalRoute {
+        match self.route() {
+            RegionalRoute::SEA => RegionalRoute::ASIA,
+            route => route,
+        }
+    }
+
+    /// Language the game client of this region uses by default
+    #[inline]
+    pub fn default_language(&self) -> LanguageCode {
+        match self.0 {
+            BR => LanguageCode::BRAZIL,
+            EUNE => LanguageCode::UNITED_KINGDOM,
+            EUW => LanguageCode::UNITED_KINGDOM,
+            JP => LanguageCode::JAPAN,
+            KR => LanguageCode::KOREA,
+            LAN => LanguageCode::MEXICO,
+            LAS => LanguageCode::ARGENTINA,
+            NA => LanguageCode::UNITED_STATES,
+            OCE => LanguageCode::AUSTRALIA,
+            TR => LanguageCode::TURKEY,
+            RU => LanguageCode::RUSSIA,
+            PBE => LanguageCode::UNITED_STATES,
+            PH => LanguageCode::PHILIPINNES,
+            SG => LanguageCode::SINGAPORE,
+            TH => LanguageCode::THAILAND,
+            TW => LanguageCode::TAIWAN,
+            VN => LanguageCode::VIETNAM,
+            ME => LanguageCode::UNITED_STATES,
+        }
+    }
+
+    /// Iterates over all region values
+    pub fn all() -> impl Iterator<Item = Region> {
+        ALL.iter().cloned()
+    }
 }
 
 impl AsRef<str> for Region {
@@ -100,12 +230,73 @@ impl Default for Region {
     }
 }
 
+/// Parses region names case-insensitively, accepting both `EUW` and platform ids like `EUW1`.
+impl FromStr for Region {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<Region, ClientError> {
+        Region::all()
+            .find(|region| {
+                region.as_str().eq_ignore_ascii_case(s)
+                    || region.as_platform_str().eq_ignore_ascii_case(s)
+            })
+            .context(UnknownConstant {
+                kind: "region",
+                value: s,
+            })
+    }
+}
+
+impl Serialize for Region {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Region {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Region, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Region;
+    use crate::constants::{LanguageCode, RegionalRoute};
 
     #[test]
     fn region_gets_correct_platform_string() {
         assert_eq!(Region::NA.as_platform_str(), "NA1")
     }
+
+    #[test]
+    fn parses_region_and_platform_names() {
+        assert_eq!("euw".parse::<Region>().unwrap(), Region::EUW);
+        assert_eq!("EUW1".parse::<Region>().unwrap(), Region::EUW);
+        assert_eq!("oc1".parse::<Region>().unwrap(), Region::OCE);
+        assert!("EUW2".parse::<Region>().is_err());
+        assert_eq!(Region::all().count(), 18);
+    }
+
+    #[test]
+    fn region_round_trips_through_serde() {
+        let json = serde_json::to_string(&Region::LAN).unwrap();
+        assert_eq!(json, "\"LAN\"");
+        assert_eq!(
+            serde_json::from_str::<Region>("\"la1\"").unwrap(),
+            Region::LAN
+        );
+    }
+
+    #[test]
+    fn maps_platforms_to_routes_and_languages() {
+        assert_eq!(Region::EUW.route(), RegionalRoute::EUROPE);
+        assert_eq!(Region::OCE.route(), RegionalRoute::SEA);
+        assert_eq!(Region::OCE.account_route(), RegionalRoute::ASIA);
+        assert_eq!(Region::EUW.account_route(), RegionalRoute::EUROPE);
+        assert_eq!(Region::VN.as_platform_str(), "VN2");
+        assert_eq!("tw2".parse::<Region>().unwrap(), Region::TW);
+        assert_eq!(Region::KR.default_language(), LanguageCode::KOREA);
+    }
 }
@@ -0,0 +1,153 @@
+use self::Inner::*;
+use crate::error::{ClientError, UnknownConstant};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use snafu::OptionExt;
+use std::convert::AsRef;
+use std::fmt;
+use std::str::FromStr;
+
+/// Regional routing value used by the match, account and tournament apis instead of a platform.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RegionalRoute(Inner);
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Inner {
+    Americas,
+    Europe,
+    Asia,
+    Sea,
+}
+
+const ALL: [RegionalRoute; 4] = [
+    RegionalRoute::AMERICAS,
+    RegionalRoute::EUROPE,
+    RegionalRoute::ASIA,
+    RegionalRoute::SEA,
+];
+
+impl RegionalRoute {
+    /// North and South America
+    pub const AMERICAS: RegionalRoute = RegionalRoute(Americas);
+    /// Europe, Turkey, Russia and the Middle East
+    pub const EUROPE: RegionalRoute = RegionalRoute(Europe);
+    /// Korea and Japan
+    pub const ASIA: RegionalRoute = RegionalRoute(Asia);
+    /// Oceania and South East Asia
+    pub const SEA: RegionalRoute = RegionalRoute(Sea);
+
+    /// String representation of a regional route
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self.0 {
+            Americas => "AMERICAS",
+            Europe => "EUROPE",
+            Asia => "ASIA",
+            Sea => "SEA",
+        }
+    }
+
+    /// Host serving the apis of this route, e.g. `europe.api.riotgames.com`
+    pub fn host(&self) -> String {
+        format!("{}.api.riotgames.com", self.as_str().to_lowercase())
+    }
+
+    /// Iterates over all regional route values
+    pub fn all() -> impl Iterator<Item = RegionalRoute> {
+        ALL.iter().cloned()
+    }
+}
+
+impl AsRef<str> for RegionalRoute {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for RegionalRoute {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl PartialEq<&str> for RegionalRoute {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_ref() == *other
+    }
+}
+
+impl PartialEq<RegionalRoute> for str {
+    #[inline]
+    fn eq(&self, other: &RegionalRoute) -> bool {
+        self == other.as_ref()
+    }
+}
+
+impl fmt::Debug for RegionalRoute {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+impl fmt::Display for RegionalRoute {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+/// Parses regional route names such as `europe`, ignoring case.
+impl FromStr for RegionalRoute {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<RegionalRoute, ClientError> {
+        RegionalRoute::all()
+            .find(|route| route.as_str().eq_ignore_ascii_case(s))
+            .context(UnknownConstant {
+                kind: "regional route",
+                value: s,
+            })
+    }
+}
+
+impl Serialize for RegionalRoute {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RegionalRoute {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RegionalRoute, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RegionalRoute;
+
+    #[test]
+    fn builds_route_host() {
+        assert_eq!(RegionalRoute::SEA.host(), "sea.api.riotgames.com");
+        assert_eq!(RegionalRoute::EUROPE, "EUROPE")
+    }
+
+    #[test]
+    fn regional_route_round_trips_through_serde() {
+        let json = serde_json::to_string(&RegionalRoute::AMERICAS).unwrap();
+        assert_eq!(json, "\"AMERICAS\"");
+        assert_eq!(
+            serde_json::from_str::<RegionalRoute>("\"asia\"").unwrap(),
+            RegionalRoute::ASIA
+        );
+        assert_eq!(
+            "Europe".parse::<RegionalRoute>().unwrap(),
+            RegionalRoute::EUROPE
+        );
+        assert!("esports".parse::<RegionalRoute>().is_err());
+        assert_eq!(RegionalRoute::all().count(), 4);
+    }
+}
@@ -0,0 +1,191 @@
+use self::Inner::*;
+use crate::error::{ClientError, UnknownConstant};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use snafu::OptionExt;
+use std::convert::AsRef;
+use std::fmt;
+use std::str::FromStr;
+
+/// Resource a champion's abilities use, as found in ddragon `partype`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ResourceType(Inner);
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Inner {
+    Mana,
+    Energy,
+    NoResource,
+    BloodWell,
+    Fury,
+    Rage,
+    Heat,
+    Ferocity,
+    Courage,
+    Shield,
+    CrimsonRush,
+    Flow,
+    Grit,
+}
+
+const ALL: [ResourceType; 13] = [
+    ResourceType::MANA,
+    ResourceType::ENERGY,
+    ResourceType::NONE,
+    ResourceType::BLOOD_WELL,
+    ResourceType::FURY,
+    ResourceType::RAGE,
+    ResourceType::HEAT,
+    ResourceType::FEROCITY,
+    ResourceType::COURAGE,
+    ResourceType::SHIELD,
+    ResourceType::CRIMSON_RUSH,
+    ResourceType::FLOW,
+    ResourceType::GRIT,
+];
+
+impl ResourceType {
+    /// Mana
+    pub const MANA: ResourceType = ResourceType(Mana);
+    /// Energy
+    pub const ENERGY: ResourceType = ResourceType(Energy);
+    /// No resource
+    pub const NONE: ResourceType = ResourceType(NoResource);
+    /// Blood Well (Aatrox)
+    pub const BLOOD_WELL: ResourceType = ResourceType(BloodWell);
+    /// Fury
+    pub const FURY: ResourceType = ResourceType(Fury);
+    /// Rage
+    pub const RAGE: ResourceType = ResourceType(Rage);
+    /// Heat (Rumble)
+    pub const HEAT: ResourceType = ResourceType(Heat);
+    /// Ferocity (Rengar)
+    pub const FEROCITY: ResourceType = ResourceType(Ferocity);
+    /// Courage (Kled)
+    pub const COURAGE: ResourceType = ResourceType(Courage);
+    /// Shield (Mordekaiser)
+    pub const SHIELD: ResourceType = ResourceType(Shield);
+    /// Crimson Rush (Vladimir)
+    pub const CRIMSON_RUSH: ResourceType = ResourceType(CrimsonRush);
+    /// Flow (Yasuo)
+    pub const FLOW: ResourceType = ResourceType(Flow);
+    /// Grit (Sett)
+    pub const GRIT: ResourceType = ResourceType(Grit);
+
+    /// Ddragon name of a resource type
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self.0 {
+            Mana => "Mana",
+            Energy => "Energy",
+            NoResource => "None",
+            BloodWell => "Blood Well",
+            Fury => "Fury",
+            Rage => "Rage",
+            Heat => "Heat",
+            Ferocity => "Ferocity",
+            Courage => "Courage",
+            Shield => "Shield",
+            CrimsonRush => "Crimson Rush",
+            Flow => "Flow",
+            Grit => "Grit",
+        }
+    }
+
+    /// Iterates over all resource type values
+    pub fn all() -> impl Iterator<Item = ResourceType> {
+        ALL.iter().cloned()
+    }
+}
+
+impl AsRef<str> for ResourceType {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for ResourceType {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl PartialEq<&str> for ResourceType {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_ref() == *other
+    }
+}
+
+impl PartialEq<ResourceType> for str {
+    #[inline]
+    fn eq(&self, other: &ResourceType) -> bool {
+        self == other.as_ref()
+    }
+}
+
+impl fmt::Debug for ResourceType {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+impl FromStr for ResourceType {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<ResourceType, ClientError> {
+        ResourceType::all()
+            .find(|value| value.as_str().eq_ignore_ascii_case(s))
+            .context(UnknownConstant {
+                kind: "resource type",
+                value: s,
+            })
+    }
+}
+
+impl Serialize for ResourceType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ResourceType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ResourceType, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResourceType;
+
+    #[test]
+    fn parses_resource_names() {
+        assert_eq!(
+            "Blood Well".parse::<ResourceType>().unwrap(),
+            ResourceType::BLOOD_WELL
+        );
+        assert!("".parse::<ResourceType>().is_err());
+        assert_eq!(ResourceType::NONE, "None")
+    }
+
+    #[test]
+    fn resource_type_round_trips_through_serde() {
+        let resource: ResourceType = serde_json::from_str("\"crimson rush\"").unwrap();
+        assert_eq!(resource, ResourceType::CRIMSON_RUSH);
+        assert_eq!(
+            serde_json::to_string(&resource).unwrap(),
+            "\"Crimson Rush\""
+        );
+        assert_eq!(ResourceType::all().count(), 13);
+    }
+}
@@ -0,0 +1,174 @@
+//! In-memory lookup of champions by numeric key, id or localized name.
+//!
+//! Riot API endpoints return numeric champion ids (e.g. [`ChampionMastery::champion_id`]) while
+//! ddragon files are keyed by string ids such as `"MonkeyKing"`. [`ChampionIndex`] bridges the two
+//! and also supports fuzzy searching by display name.
+//!
+//! [`ChampionMastery::champion_id`]: ../../dto/api/struct.ChampionMastery.html#structfield.champion_id
+//! [`ChampionIndex`]: struct.ChampionIndex.html
+use crate::dto::ddragon::{AllChampions, ChampionData};
+
+use std::collections::HashMap;
+
+/// Minimal score a champion needs to be returned from [`ChampionIndex::search`].
+///
+/// [`ChampionIndex::search`]: struct.ChampionIndex.html#method.search
+const MIN_SEARCH_SCORE: f64 = 0.7;
+
+/// Champion lookup table built from [`AllChampions`].
+///
+/// [`AllChampions`]: ../../dto/ddragon/struct.AllChampions.html
+#[derive(Debug, Clone)]
+pub struct ChampionIndex {
+    champions: Vec<ChampionData>,
+    by_key: HashMap<i64, usize>,
+    by_id: HashMap<String, usize>,
+    by_name: HashMap<String, usize>,
+}
+
+/// A single search result of [`ChampionIndex::search`].
+///
+/// [`ChampionIndex::search`]: struct.ChampionIndex.html#method.search
+#[derive(Debug, Clone)]
+pub struct ChampionMatch<'a> {
+    pub champion: &'a ChampionData,
+    /// Similarity between the query and the champion in `0.0..=1.0`, `1.0` being an exact match.
+    pub score: f64,
+}
+
+impl ChampionIndex {
+    /// Builds the index from the champion list returned by [`DDragonClient::get_champions`].
+    ///
+    /// [`DDragonClient::get_champions`]: ../struct.DDragonClient.html#method.get_champions
+    pub fn new(champions: &AllChampions) -> ChampionIndex {
+        let mut champions: Vec<ChampionData> = champions.data.values().cloned().collect();
+        champions.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut by_key = HashMap::new();
+        let mut by_id = HashMap::new();
+        let mut by_name = HashMap::new();
+        for (i, champion) in champions.iter().enumerate() {
+            if let Ok(key) = champion.key.parse::<i64>() {
+                by_key.insert(key, i);
+            }
+            by_id.insert(normalize(&champion.id), i);
+            by_name.insert(normalize(&champion.name), i);
+        }
+
+        ChampionIndex {
+            champions,
+            by_key,
+            by_id,
+            by_name,
+        }
+    }
+
+    /// All indexed champions sorted by id.
+    pub fn champions(&self) -> &[ChampionData] {
+        &self.champions
+    }
+
+    /// Finds a champion by numeric key, e.g. `64` for Lee Sin.
+    pub fn by_key(&self, key: i64) -> Option<&ChampionData> {
+        self.by_key.get(&key).map(|&i| &self.champions[i])
+    }
+
+    /// Finds a champion by ddragon id ignoring case, e.g. `"monkeyking"`.
+    pub fn by_id(&self, id: &str) -> Option<&ChampionData> {
+        self.by_id.get(&normalize(id)).map(|&i| &self.champions[i])
+    }
+
+    /// Finds a champion by localized display name ignoring case, spaces and punctuation,
+    /// e.g. `"lee sin"` or `"Kai'Sa"`.
+    pub fn by_name(&self, name: &str) -> Option<&ChampionData> {
+        self.by_name
+            .get(&normalize(name))
+            .map(|&i| &self.champions[i])
+    }
+
+    /// Finds a champion by either id or display name, ignoring case.
+    pub fn get(&self, query: &str) -> Option<&ChampionData> {
+        self.by_id(query).or_else(|| self.by_name(query))
+    }
+
+    /// Fuzzy searches champions by id and display name.
+    ///
+    /// Results are sorted by descending score and limited to `limit` entries.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<ChampionMatch<'_>> {
+        let query = normalize(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<ChampionMatch<'_>> = self
+            .champions
+            .iter()
+            .map(|champion| {
+                let score = similarity(&query, &normalize(&champion.id))
+                    .max(similarity(&query, &normalize(&champion.name)));
+                ChampionMatch { champion, score }
+            })
+            .filter(|m| m.score >= MIN_SEARCH_SCORE)
+            .collect();
+        matches.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap()
+                .then_with(|| a.champion.id.cmp(&b.champion.id))
+        });
+        matches.truncate(limit);
+        matches
+    }
+}
+
+/// Lowercases and strips everything but letters and digits, so `"Kai'Sa"` becomes `"kaisa"`.
+fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn similarity(query: &str, candidate: &str) -> f64 {
+    if query == candidate {
+        1.0
+    } else if candidate.starts_with(query) {
+        // Prefixes like "mord" should always beat champions that merely look similar
+        0.9 + 0.1 * query.len() as f64 / candidate.len() as f64
+    } else {
+        strsim::jaro_winkler(query, candidate) * 0.9
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChampionIndex;
+    use crate::dto::ddragon::AllChampions;
+
+    fn index() -> ChampionIndex {
+        let champions: AllChampions = serde_json::from_str(include_str!(
+            "../../tests/data/dragontail/10.10.1/data/en_US/champion.json"
+        ))
+        .unwrap();
+        ChampionIndex::new(&champions)
+    }
+
+    #[test]
+    fn looks_up_by_key_id_and_name() {
+        let index = index();
+        assert_eq!(index.by_key(64).unwrap().id, "LeeSin");
+        assert_eq!(index.by_id("monkeyking").unwrap().name, "Wukong");
+        assert_eq!(index.by_name("lee sin").unwrap().id, "LeeSin");
+        assert_eq!(index.get("WUKONG").unwrap().id, "MonkeyKing");
+        assert!(index.by_key(9999).is_none());
+    }
+
+    #[test]
+    fn fuzzy_search_ranks_suggestions() {
+        let index = index();
+        assert_eq!(index.search("wukong", 3)[0].champion.id, "MonkeyKing");
+        assert_eq!(index.search("lee", 3)[0].champion.id, "LeeSin");
+        assert_eq!(index.search("anie", 3)[0].champion.id, "Annie");
+        assert!(index.search("zzzzzz", 3).is_empty());
+    }
+}
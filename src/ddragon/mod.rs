@@ -0,0 +1,905 @@
+use crate::constants::{Champion, LanguageCode, Region};
+use crate::de;
+use crate::dto::ddragon::{
+    AllChampions, AllItems, ChampionExtended, ChampionFullData, FullChampions, Realm,
+};
+use crate::dto::static_data::{GameModeData, GameTypeData, MapData, QueueData, SeasonData};
+use crate::error::{
+    ChampionNotFound, ClientError, IOError, InvalidDragontail, QueueNotFound, RequestInfo,
+    VersionNotFound,
+};
+use crate::types::{Cache, Client};
+use crate::utils::{
+    construct_hyper_client, ddragon_realm_url, ddragon_version_from_game_version, fetch,
+    find_dragontail_version, get_ddragon_realm, get_ddragon_versions, get_latest_ddragon_version,
+    unblock, unpack_dragontail, CachedClient,
+};
+use log::debug;
+
+use futures::prelude::*;
+use hyper::{Body, Request, Uri};
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+
+use hyper::header::HeaderValue;
+use parking_lot::{Mutex, RwLock};
+use serde::de::DeserializeOwned;
+use snafu::{ensure, OptionExt, ResultExt};
+use std::fmt::Debug;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+pub mod champion_index;
+pub mod patch_diff;
+pub mod skins;
+pub mod spell_table;
+pub mod stats;
+pub mod tooltip;
+pub mod watcher;
+
+pub use champion_index::{ChampionIndex, ChampionMatch};
+pub use patch_diff::{Change, PatchData, PatchDiff};
+pub use skins::{Skin, SkinCatalogue};
+pub use spell_table::{scaling_table, SpellRankValues};
+pub use stats::{ChampionStats, StatCalculator};
+pub use tooltip::{RenderedTooltip, SpellTooltip, TooltipFormat};
+pub use watcher::{VersionEvent, VersionWatcher};
+
+const CDN_URL: &str = "https://ddragon.leagueoflegends.com/cdn";
+/// Default number of champion files fetched at once by bulk requests.
+pub const DEFAULT_CONCURRENCY: usize = 16;
+/// Location of `queues.json`, `maps.json` and other static game constants.
+const STATIC_DATA_URL: &str = "https://static.developer.riotgames.com/docs/lol";
+
+/// Url of versioned data files for a language, e.g. `champion.json` lives under it.
+fn data_url(version: &str, language: &LanguageCode) -> String {
+    format!("{}/{}/data/{}", CDN_URL, version, language)
+}
+
+/// A champion [`DDragonClient::get_champion`] can look up: a ddragon id like `"LeeSin"` or a
+/// [`Champion`].
+///
+/// [`DDragonClient::get_champion`]: struct.DDragonClient.html#method.get_champion
+/// [`Champion`]: ../constants/champion/enum.Champion.html
+pub trait ChampionRef {
+    /// The ddragon id, or the numeric key of champions without a known id
+    fn ddragon_id(&self) -> Result<&str, i64>;
+}
+
+impl ChampionRef for &str {
+    fn ddragon_id(&self) -> Result<&str, i64> {
+        Ok(self)
+    }
+}
+
+impl ChampionRef for String {
+    fn ddragon_id(&self) -> Result<&str, i64> {
+        Ok(self)
+    }
+}
+
+impl ChampionRef for &String {
+    fn ddragon_id(&self) -> Result<&str, i64> {
+        Ok(self)
+    }
+}
+
+impl ChampionRef for Champion {
+    fn ddragon_id(&self) -> Result<&str, i64> {
+        self.id().ok_or_else(|| self.key())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DDragonClient {
+    client: Client,
+    cache: Cache,
+    /// Shared with clones and `lang` clients, so `set_version` switches all of them
+    version: Arc<RwLock<String>>,
+    language: LanguageCode,
+    dragontail: Option<PathBuf>,
+    lenient: bool,
+}
+
+impl DDragonClient {
+    pub async fn new(language: LanguageCode) -> Result<DDragonClient, ClientError> {
+        let client = construct_hyper_client();
+        let cache: Cache = Arc::new(Mutex::new(HashMap::new()));
+        let version = get_latest_ddragon_version(client.clone()).await?;
+        Ok(DDragonClient {
+            version: Arc::new(RwLock::new(version)),
+            language,
+            client,
+            cache,
+            dragontail: None,
+            lenient: false,
+        })
+    }
+
+    /// Creates a client pinned to a specific ddragon `version` (e.g. `10.10.1`) instead of the latest one.
+    pub fn with_version(language: LanguageCode, version: &str) -> DDragonClient {
+        let client = construct_hyper_client();
+        let cache: Cache = Arc::new(Mutex::new(HashMap::new()));
+        DDragonClient {
+            version: Arc::new(RwLock::new(version.to_owned())),
+            language,
+            client,
+            cache,
+            dragontail: None,
+            lenient: false,
+        }
+    }
+
+    /// Creates a client pinned to the ddragon version that matches a match `gameVersion`
+    /// string such as `13.5.497.1234`.
+    pub async fn for_game_version(
+        language: LanguageCode,
+        game_version: &str,
+    ) -> Result<DDragonClient, ClientError> {
+        let client = construct_hyper_client();
+        let versions = get_ddragon_versions(client.clone()).await?;
+        let version = resolve_game_version(&versions, game_version)?;
+        let mut ddragon = DDragonClient::with_version(language, &version);
+        ddragon.client = client;
+        Ok(ddragon)
+    }
+
+    /// Creates a client using the data version and default language `region` is currently running.
+    ///
+    /// Regions roll out patches at different times, so this can lag behind [`new`].
+    ///
+    /// [`new`]: #method.new
+    pub async fn for_region(region: Region) -> Result<DDragonClient, ClientError> {
+        let client = construct_hyper_client();
+        let realm = get_ddragon_realm(client.clone(), &region).await?;
+        let language = realm.language.parse().unwrap_or_else(|_| {
+            let language = region.default_language();
+            debug!(
+                "Unknown realm language {}, using {}",
+                &realm.language, &language
+            );
+            language
+        });
+        let mut ddragon = DDragonClient::with_version(language, &realm.dd);
+        ddragon.client = client;
+        Ok(ddragon)
+    }
+
+    pub(crate) async fn new_for_lapi(
+        client: Client,
+        cache: Cache,
+        lang: LanguageCode,
+    ) -> Result<DDragonClient, ClientError> {
+        let version = get_latest_ddragon_version(client.clone()).await?;
+        Ok(DDragonClient {
+            version: Arc::new(RwLock::new(version)),
+            language: lang,
+            client,
+            cache,
+            dragontail: None,
+            lenient: false,
+        })
+    }
+
+    /// Creates a client that serves versioned data from a local
+    /// [dragontail](https://ddragon.leagueoflegends.com/cdn/dragontail-10.10.1.tgz) copy without network access.
+    ///
+    /// Dragontail only contains the cdn, so realms and static game constants such as [`get_queues`]
+    /// are still fetched from the network.
+    ///
+    /// `path` can either be an already extracted directory or the `.tgz` archive itself. Archives are
+    /// unpacked next to the archive file on first use, on the runtime's blocking thread pool.
+    ///
+    /// [`get_queues`]: #method.get_queues
+    pub async fn from_dragontail<P: AsRef<Path>>(
+        path: P,
+        language: LanguageCode,
+    ) -> Result<DDragonClient, ClientError> {
+        let path = path.as_ref().to_owned();
+        let root = if path.is_dir() {
+            path
+        } else {
+            unblock(move || unpack_dragontail(&path)).await?
+        };
+        let version =
+            find_dragontail_version(&root).context(InvalidDragontail { path: root.clone() })?;
+        let mut ddragon = DDragonClient::with_version(language, &version);
+        ddragon.dragontail = Some(root);
+        Ok(ddragon)
+    }
+
+    /// Tolerates changes in ddragon's data format.
+    ///
+    /// Fields that fail to deserialize are dropped with a warning instead of failing the request,
+    /// so optional and defaulted fields fall back to their defaults.
+    pub fn lenient(self, lenient: bool) -> Self {
+        DDragonClient { lenient, ..self }
+    }
+
+    /// Version of ddragon data this client is bound to.
+    pub fn version(&self) -> String {
+        self.version.read().clone()
+    }
+
+    /// Language of data this client fetches.
+    pub fn language(&self) -> &LanguageCode {
+        &self.language
+    }
+
+    /// Returns a client for another language that shares version, http client and cache with this one.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use narwhalol::ddragon::DDragonClient;
+    /// use narwhalol::LanguageCode;
+    ///
+    /// smol::run(async {
+    ///     let mut ddragon = DDragonClient::new(LanguageCode::UNITED_STATES).await.unwrap();
+    ///     let ahri = ddragon.lang(LanguageCode::KOREA).get_champion("Ahri").await.unwrap();
+    ///     println!("{}", ahri.name);
+    /// })
+    /// ```
+    pub fn lang(&self, language: LanguageCode) -> DDragonClient {
+        DDragonClient {
+            language,
+            ..self.clone()
+        }
+    }
+
+    /// Gets data versions and default language `region` is currently running.
+    pub async fn get_realm(&mut self, region: Region) -> Result<Realm, ClientError> {
+        self.cached_resp(ddragon_realm_url(&region)).await
+    }
+
+    /// Returns a client for another ddragon version that shares language, http client and cache with this one.
+    ///
+    /// The returned client keeps its version when this one is switched by [`set_version`].
+    ///
+    /// [`set_version`]: #method.set_version
+    pub fn at_version(&self, version: &str) -> DDragonClient {
+        DDragonClient {
+            version: Arc::new(RwLock::new(version.to_owned())),
+            ..self.clone()
+        }
+    }
+
+    /// Switches this client to another ddragon version and evicts the data files of the previous
+    /// version from the cache.
+    ///
+    /// The version is shared with clones of this client and clients created by [`lang`], so they
+    /// switch as well. Clients created by [`at_version`] keep their version. Other cached responses,
+    /// including riot api responses of a [`LeagueClient`] sharing the cache, are kept.
+    ///
+    /// [`lang`]: #method.lang
+    /// [`at_version`]: #method.at_version
+    /// [`LeagueClient`]: ../api/struct.LeagueClient.html
+    pub fn set_version(&mut self, version: &str) {
+        let previous = std::mem::replace(&mut *self.version.write(), version.to_owned());
+        if previous == version {
+            return;
+        }
+        let prefix = format!("{}/{}/", CDN_URL, previous);
+        self.cache
+            .lock()
+            .retain(|url, _| !url.to_string().starts_with(&prefix));
+    }
+
+    /// Url of versioned data files for this client's version and language.
+    fn base_url(&self) -> String {
+        data_url(&self.version.read(), &self.language)
+    }
+
+    /// Fetches every champion and all items of this client's version for comparison with [`PatchDiff`].
+    ///
+    /// [`PatchDiff`]: patch_diff/struct.PatchDiff.html
+    pub async fn get_patch_data(&mut self) -> Result<PatchData, ClientError> {
+        let champions = self
+            .get_all_champions_full(DEFAULT_CONCURRENCY)
+            .await?
+            .into_map();
+        let items = self.get_items().await?;
+        Ok(PatchData {
+            version: self.version(),
+            champions,
+            items,
+        })
+    }
+
+    /// Compares champion and item data of two ddragon versions, e.g. `10.9.1` and `10.10.1`.
+    pub async fn get_patch_diff(&self, from: &str, to: &str) -> Result<PatchDiff, ClientError> {
+        let old = self.at_version(from).get_patch_data().await?;
+        let new = self.at_version(to).get_patch_data().await?;
+        Ok(PatchDiff::new(&old, &new))
+    }
+
+    /// Lists language codes ddragon has data for, e.g. `en_US`.
+    pub async fn get_languages(&mut self) -> Result<Vec<String>, ClientError> {
+        let url: Uri = format!("{}/languages.json", CDN_URL).parse().unwrap();
+        self.cached_resp(url).await
+    }
+
+    /// Checks whether ddragon has data for `language`.
+    pub async fn supports_language(
+        &mut self,
+        language: &LanguageCode,
+    ) -> Result<bool, ClientError> {
+        let languages = self.get_languages().await?;
+        Ok(languages.iter().any(|l| l.as_str() == language.as_str()))
+    }
+
+    /// Lists all ddragon versions, newest first.
+    ///
+    /// Clients created with [`from_dragontail`] only know the version of their archive.
+    ///
+    /// [`from_dragontail`]: #method.from_dragontail
+    pub async fn list_versions(&self) -> Result<Vec<String>, ClientError> {
+        if self.dragontail.is_some() {
+            return Ok(vec![self.version()]);
+        }
+        get_ddragon_versions(self.client.clone()).await
+    }
+
+    /// Finds the ddragon version matching a match `gameVersion` string such as `13.5.497.1234`.
+    pub async fn version_for_game(&self, game_version: &str) -> Result<String, ClientError> {
+        let versions = self.list_versions().await?;
+        resolve_game_version(&versions, game_version)
+    }
+
+    pub async fn get_champions(&mut self) -> Result<AllChampions, ClientError> {
+        let url: Uri = format!("{}/champion.json", self.base_url())
+            .parse()
+            .unwrap();
+        self.cached_resp(url).await
+    }
+
+    /// Gets full champion data by ddragon id like `"LeeSin"` or by [`Champion`].
+    ///
+    /// [`Champion::Unknown`] has no ddragon id and is looked up by [`get_champion_by_key`] instead.
+    ///
+    /// [`Champion`]: ../constants/champion/enum.Champion.html
+    /// [`Champion::Unknown`]: ../constants/champion/enum.Champion.html#variant.Unknown
+    /// [`get_champion_by_key`]: #method.get_champion_by_key
+    pub async fn get_champion<C: ChampionRef>(
+        &mut self,
+        champion: C,
+    ) -> Result<ChampionFullData, ClientError> {
+        match champion.ddragon_id() {
+            Ok(id) => {
+                let id = id.to_owned();
+                self.fetch_champion(&id).await
+            }
+            Err(key) => self.get_champion_by_key(key).await,
+        }
+    }
+
+    async fn fetch_champion(&mut self, id: &str) -> Result<ChampionFullData, ClientError> {
+        ensure!(!id.is_empty(), ChampionNotFound { query: id });
+        let url: Uri = format!("{}/champion/{}.json", self.base_url(), id)
+            .parse()
+            .unwrap();
+        let mut resp = self.cached_resp::<ChampionExtended>(url).await?;
+        resp.data.remove(id).context(ChampionNotFound { query: id })
+    }
+
+    /// Fetches full data of every champion, at most `concurrency` files at a time.
+    ///
+    /// Responses end up in the shared cache, so later [`get_champion`] calls don't hit the network.
+    ///
+    /// [`get_champion`]: #method.get_champion
+    pub async fn get_all_champions_full(
+        &mut self,
+        concurrency: usize,
+    ) -> Result<FullChampions, ClientError> {
+        let champion_ids: Vec<String> = self.get_champions().await?.data.keys().cloned().collect();
+        let champions: Vec<ChampionFullData> = stream::iter(champion_ids)
+            .map(|id| {
+                let mut client = self.clone();
+                async move { client.get_champion(&id).await }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .try_collect()
+            .await?;
+        Ok(FullChampions::new(champions))
+    }
+
+    pub async fn get_items(&mut self) -> Result<AllItems, ClientError> {
+        let url: Uri = format!("{}/item.json", self.base_url()).parse().unwrap();
+        self.cached_resp(url).await
+    }
+
+    /// Builds a [`ChampionIndex`] from [`get_champions`] for lookups by numeric key and fuzzy name search.
+    ///
+    /// [`ChampionIndex`]: champion_index/struct.ChampionIndex.html
+    /// [`get_champions`]: #method.get_champions
+    pub async fn get_champion_index(&mut self) -> Result<ChampionIndex, ClientError> {
+        let champions = self.get_champions().await?;
+        Ok(ChampionIndex::new(&champions))
+    }
+
+    /// Gets full champion data by numeric key as returned by riot api, e.g. `64` for Lee Sin.
+    pub async fn get_champion_by_key(&mut self, key: i64) -> Result<ChampionFullData, ClientError> {
+        let index = self.get_champion_index().await?;
+        let id = index
+            .by_key(key)
+            .map(|champion| champion.id.clone())
+            .context(ChampionNotFound {
+                query: key.to_string(),
+            })?;
+        self.fetch_champion(&id).await
+    }
+
+    /// Builds a [`SkinCatalogue`] of every champion's skins.
+    ///
+    /// All champion files are fetched through [`get_all_champions_full`].
+    ///
+    /// [`get_all_champions_full`]: #method.get_all_champions_full
+    /// [`SkinCatalogue`]: skins/struct.SkinCatalogue.html
+    pub async fn get_skin_catalogue(&mut self) -> Result<SkinCatalogue, ClientError> {
+        let champions = self.get_all_champions_full(DEFAULT_CONCURRENCY).await?;
+        Ok(SkinCatalogue::new(champions.iter()))
+    }
+
+    /// Gets all queues from `queues.json`.
+    pub async fn get_queues(&mut self) -> Result<Vec<QueueData>, ClientError> {
+        self.cached_resp(static_data_url("queues.json")).await
+    }
+
+    /// Gets queue data for a match `queueId`, e.g. `420` for ranked solo.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use narwhalol::ddragon::DDragonClient;
+    /// use narwhalol::LanguageCode;
+    ///
+    /// smol::run(async {
+    ///     let mut ddragon = DDragonClient::new(LanguageCode::UNITED_STATES).await.unwrap();
+    ///     let queue = ddragon.get_queue(420).await.unwrap();
+    ///     assert_eq!(queue.describe(), "5v5 Ranked Solo games on Summoner's Rift");
+    /// })
+    /// ```
+    pub async fn get_queue(&mut self, queue_id: i64) -> Result<QueueData, ClientError> {
+        let queues = self.get_queues().await?;
+        queues
+            .into_iter()
+            .find(|queue| queue.queue_id == queue_id)
+            .context(QueueNotFound { queue_id })
+    }
+
+    /// Gets all maps from `maps.json`.
+    pub async fn get_maps(&mut self) -> Result<Vec<MapData>, ClientError> {
+        self.cached_resp(static_data_url("maps.json")).await
+    }
+
+    /// Gets all game modes from `gameModes.json`.
+    pub async fn get_game_modes(&mut self) -> Result<Vec<GameModeData>, ClientError> {
+        self.cached_resp(static_data_url("gameModes.json")).await
+    }
+
+    /// Gets all game types from `gameTypes.json`.
+    pub async fn get_game_types(&mut self) -> Result<Vec<GameTypeData>, ClientError> {
+        self.cached_resp(static_data_url("gameTypes.json")).await
+    }
+
+    /// Gets all seasons from `seasons.json`.
+    pub async fn get_seasons(&mut self) -> Result<Vec<SeasonData>, ClientError> {
+        self.cached_resp(static_data_url("seasons.json")).await
+    }
+}
+
+/// Local copy of `url` inside an extracted dragontail, `None` for data dragontail doesn't contain.
+///
+/// Dragontail mirrors everything below the cdn root, e.g. `10.10.1/data/en_US/champion.json`, but
+/// neither realms nor static game constants.
+fn dragontail_path(root: &Path, url: &Uri) -> Option<PathBuf> {
+    let url = url.to_string();
+    if !url.starts_with(CDN_URL) {
+        return None;
+    }
+    let relative = Path::new(url[CDN_URL.len()..].trim_start_matches('/'));
+    // Versions and champion ids end up in the url, keep them from escaping the root
+    if !relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return None;
+    }
+    Some(root.join(relative))
+}
+
+fn static_data_url(file: &str) -> Uri {
+    format!("{}/{}", STATIC_DATA_URL, file).parse().unwrap()
+}
+
+/// Picks the newest version from `versions` belonging to the same `major.minor` patch as `game_version`.
+fn resolve_game_version(versions: &[String], game_version: &str) -> Result<String, ClientError> {
+    let patch = ddragon_version_from_game_version(game_version).context(VersionNotFound {
+        version: game_version.to_owned(),
+    })?;
+    let prefix = &patch[..patch.len() - 1];
+    versions
+        .iter()
+        .find(|v| v.starts_with(prefix))
+        .cloned()
+        .context(VersionNotFound {
+            version: game_version.to_owned(),
+        })
+}
+
+#[async_trait]
+impl CachedClient for DDragonClient {
+    async fn cached_resp<T: Debug + DeserializeOwned + Send>(
+        &self,
+        url: Uri,
+    ) -> Result<T, ClientError> {
+        let request = RequestInfo::get(url.clone(), "ddragon");
+        let cached = self.cache.lock().get(&url).cloned();
+
+        if let Some(mut cached) = cached {
+            let resp = de::from_response(&mut cached, &request, self.lenient)?;
+            debug!("Found cached: {:?}", resp);
+            Ok(resp)
+        } else {
+            debug!("Nothing in cache. Fetching...");
+            let local = self
+                .dragontail
+                .as_ref()
+                .and_then(|root| dragontail_path(root, &url));
+            let mut string_response = match local {
+                Some(path) => fs::read_to_string(path).context(IOError)?,
+                None => {
+                    // We got nothing in cache, try fetching from utl
+                    let req = Request::builder()
+                        .uri(url.clone())
+                        .body(Default::default())
+                        .unwrap();
+                    fetch(&self.client, req, &request).await?
+                }
+            };
+            debug!("Deserializing...");
+            let deserialized: T = de::from_response(&mut string_response, &request, self.lenient)?;
+            self.cache.lock().insert(url, string_response);
+            Ok(deserialized)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::{
+        Champion, ChampionTag, GameMode, GameType, LanguageCode, Map, RankedQueue, Region,
+        ResourceType,
+    };
+    use crate::ddragon::{
+        data_url, dragontail_path, resolve_game_version, static_data_url, DDragonClient, CDN_URL,
+    };
+    use crate::dto::ddragon::{AllChampions, ChampionFullData};
+    use crate::error::ClientError;
+    use crate::utils::ddragon_realm_url;
+    use hyper::Uri;
+    use std::path::Path;
+    use std::time::Instant;
+
+    #[test]
+    fn creates_proper_instance() {
+        smol::run(async {
+            let cli = DDragonClient::new(LanguageCode::RUSSIA).await.unwrap();
+            println!("{:?}", &cli)
+        })
+    }
+
+    #[test]
+    fn resolves_game_version_against_version_list() {
+        let versions: Vec<String> = vec!["13.6.1", "13.5.2", "13.5.1", "13.4.1"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(
+            resolve_game_version(&versions, "13.5.497.1234").unwrap(),
+            "13.5.2"
+        );
+        assert!(resolve_game_version(&versions, "12.1.400.1").is_err());
+    }
+
+    #[test]
+    fn pinned_client_uses_given_version() {
+        let cli = DDragonClient::with_version(LanguageCode::UNITED_STATES, "10.10.1");
+        assert_eq!(cli.version(), "10.10.1");
+        assert!(cli.base_url().contains("/10.10.1/"));
+    }
+
+    #[test]
+    fn serves_data_from_dragontail_directory() {
+        smol::run(async {
+            let mut client = DDragonClient::from_dragontail(
+                "tests/data/dragontail",
+                LanguageCode::UNITED_STATES,
+            )
+            .await
+            .unwrap();
+            assert_eq!(client.version(), "10.10.1");
+            assert_eq!(client.list_versions().await.unwrap(), vec!["10.10.1"]);
+            let champions = client.get_champions().await.unwrap();
+            assert_eq!(champions.data["LeeSin"].key, "64");
+            let lee_sin = client.get_champion("LeeSin").await.unwrap();
+            assert_eq!(lee_sin.name, "Lee Sin");
+        })
+    }
+
+    #[test]
+    fn language_clients_share_version_and_cache() {
+        smol::run(async {
+            let mut client = DDragonClient::from_dragontail(
+                "tests/data/dragontail",
+                LanguageCode::UNITED_STATES,
+            )
+            .await
+            .unwrap();
+            let _ = client.get_champions().await.unwrap();
+            let korean = client.lang(LanguageCode::KOREA);
+            assert_eq!(korean.version(), client.version());
+            assert_eq!(korean.language(), &LanguageCode::KOREA);
+            assert!(korean.base_url().ends_with("/10.10.1/data/ko_KR"));
+            assert_eq!(korean.cache.lock().len(), 1);
+
+            assert!(client
+                .supports_language(&LanguageCode::KOREA)
+                .await
+                .unwrap());
+        })
+    }
+
+    #[test]
+    fn creates_instance_for_region() {
+        smol::run(async {
+            let mut cli = DDragonClient::for_region(Region::EUW).await.unwrap();
+            let realm = cli.get_realm(Region::EUW).await.unwrap();
+            assert_eq!(cli.version(), realm.dd);
+            assert_eq!(cli.language(), &LanguageCode::UNITED_KINGDOM);
+        })
+    }
+
+    #[test]
+    fn set_version_evicts_only_previous_version_data() {
+        let mut client = DDragonClient::with_version(LanguageCode::UNITED_STATES, "10.10.1");
+        let old_champions: Uri = format!("{}/champion.json", client.base_url())
+            .parse()
+            .unwrap();
+        let summoner: Uri =
+            "https://euw1.api.riotgames.com/lol/summoner/v4/summoners/by-name/Vetro"
+                .parse()
+                .unwrap();
+        let languages: Uri = format!("{}/languages.json", CDN_URL).parse().unwrap();
+        {
+            let mut cache = client.cache.lock();
+            for url in &[&old_champions, &summoner, &languages] {
+                cache.insert((*url).clone(), "{}".to_owned());
+            }
+        }
+        client.set_version("10.11.1");
+        let cache = client.cache.lock();
+        assert!(!cache.contains_key(&old_champions));
+        assert!(cache.contains_key(&summoner));
+        assert!(cache.contains_key(&languages));
+    }
+
+    #[test]
+    fn maps_only_cdn_urls_onto_dragontail() {
+        let root = Path::new("dragontail");
+        let champions = format!(
+            "{}/champion.json",
+            data_url("10.10.1", &LanguageCode::UNITED_STATES)
+        )
+        .parse()
+        .unwrap();
+        assert_eq!(
+            dragontail_path(root, &champions),
+            Some(root.join("10.10.1/data/en_US/champion.json"))
+        );
+        let languages = format!("{}/languages.json", CDN_URL).parse().unwrap();
+        assert_eq!(
+            dragontail_path(root, &languages),
+            Some(root.join("languages.json"))
+        );
+        assert_eq!(
+            dragontail_path(root, &ddragon_realm_url(&Region::EUW)),
+            None
+        );
+        assert_eq!(dragontail_path(root, &static_data_url("queues.json")), None);
+        let escaping = format!(
+            "{}/../../secret.json",
+            data_url("10.10.1", &LanguageCode::UNITED_STATES)
+        )
+        .parse()
+        .unwrap();
+        assert_eq!(dragontail_path(root, &escaping), None);
+    }
+
+    #[test]
+    fn gets_champion_by_numeric_key() {
+        smol::run(async {
+            let mut client = DDragonClient::from_dragontail(
+                "tests/data/dragontail",
+                LanguageCode::UNITED_STATES,
+            )
+            .await
+            .unwrap();
+            let wukong = client.get_champion_by_key(62).await.unwrap();
+            assert_eq!(wukong.id, "MonkeyKing");
+            let lee_sin = client.get_champion(Champion::LEE_SIN).await.unwrap();
+            assert_eq!(lee_sin.key, "64");
+            // Champions missing from the generated table fall back to the key lookup
+            match client.get_champion(Champion::from_key(9999)).await {
+                Err(ClientError::ChampionNotFound { query }) => assert_eq!(query, "9999"),
+                other => panic!("expected the key lookup to fail, got {:?}", other),
+            }
+            assert!(client.get_champion_by_key(-1).await.is_err());
+        })
+    }
+
+    #[test]
+    fn prefetches_all_champions_into_cache() {
+        smol::run(async {
+            let mut client = DDragonClient::from_dragontail(
+                "tests/data/dragontail",
+                LanguageCode::UNITED_STATES,
+            )
+            .await
+            .unwrap();
+            let champions = client.get_all_champions_full(2).await.unwrap();
+            assert_eq!(champions.len(), 3);
+            assert_eq!(champions.by_key(62).unwrap().id, "MonkeyKing");
+            assert_eq!(champions.by_id("LeeSin").unwrap().key, "64");
+            // champion.json and all three champion files
+            assert_eq!(client.cache.lock().len(), 4);
+
+            let cached = DDragonClient {
+                dragontail: Some("does/not/exist".into()),
+                ..client.clone()
+            };
+            assert_eq!(cached.clone().get_champion("Annie").await.unwrap().key, "1");
+        })
+    }
+
+    #[test]
+    fn builds_skin_catalogue_from_all_champions() {
+        smol::run(async {
+            let mut client = DDragonClient::from_dragontail(
+                "tests/data/dragontail",
+                LanguageCode::UNITED_STATES,
+            )
+            .await
+            .unwrap();
+            let catalogue = client.get_skin_catalogue().await.unwrap();
+            assert_eq!(catalogue.skins().len(), 9);
+            let radiant = catalogue.by_id(62005).unwrap();
+            assert_eq!(radiant.champion_name, "Wukong");
+            assert_eq!(
+                radiant.loading_url,
+                "https://ddragon.leagueoflegends.com/cdn/img/champion/loading/MonkeyKing_5.jpg"
+            );
+            assert_eq!(catalogue.with_chromas(true).count(), 3);
+        })
+    }
+
+    #[test]
+    fn filters_champions_by_tag_and_resource() {
+        smol::run(async {
+            let mut client = DDragonClient::from_dragontail(
+                "tests/data/dragontail",
+                LanguageCode::UNITED_STATES,
+            )
+            .await
+            .unwrap();
+            let champions = client.get_champions().await.unwrap();
+            let mana_tanks: Vec<&str> = champions
+                .with_tag(&ChampionTag::TANK)
+                .filter(|champion| champion.uses_resource(&ResourceType::MANA))
+                .map(|champion| champion.id.as_str())
+                .collect();
+            assert_eq!(mana_tanks, vec!["MonkeyKing"]);
+
+            let lee_sin = champions
+                .with_resource(&ResourceType::ENERGY)
+                .next()
+                .unwrap();
+            assert_eq!(lee_sin.id, "LeeSin");
+            assert_eq!(lee_sin.resource_type(), Some(ResourceType::ENERGY));
+            assert_eq!(
+                lee_sin.champion_tags(),
+                vec![ChampionTag::FIGHTER, ChampionTag::ASSASSIN]
+            );
+        })
+    }
+
+    #[test]
+    fn gets_static_game_constants() {
+        smol::run(async {
+            let mut client = DDragonClient::new(LanguageCode::UNITED_STATES)
+                .await
+                .unwrap();
+            let solo = client.get_queue(420).await.unwrap();
+            assert!(!solo.describe().is_empty());
+            assert_eq!(solo.ranked_queue(), Some(RankedQueue::SOLO));
+            assert_eq!(
+                client.get_queue(440).await.unwrap().ranked_queue(),
+                Some(RankedQueue::FLEX)
+            );
+            assert_eq!(client.get_queue(450).await.unwrap().ranked_queue(), None);
+            assert!(client.get_queue(-1).await.is_err());
+
+            let maps = client.get_maps().await.unwrap();
+            for map in maps {
+                if let Some(known) = Map::from_id(map.map_id) {
+                    assert_eq!(known, map.map_name.as_str());
+                }
+            }
+            let modes = client.get_game_modes().await.unwrap();
+            assert!(modes
+                .iter()
+                .any(|mode| GameMode::CLASSIC == mode.game_mode.as_str()));
+            let types = client.get_game_types().await.unwrap();
+            assert!(types
+                .iter()
+                .any(|game_type| GameType::MATCHED_GAME == game_type.gametype.as_str()));
+            let seasons = client.get_seasons().await.unwrap();
+            assert!(seasons
+                .iter()
+                .any(|season| season.id == 13 && season.season == "SEASON 2019"));
+        })
+    }
+
+    #[test]
+    fn serves_data_from_dragontail_archive() {
+        let dir = std::env::temp_dir().join(format!("narwhalol-dragontail-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let archive_path = dir.join("dragontail-10.10.1.tgz");
+        let archive = std::fs::File::create(&archive_path).unwrap();
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+            archive,
+            flate2::Compression::fast(),
+        ));
+        builder
+            .append_dir_all(".", "tests/data/dragontail")
+            .unwrap();
+        builder.into_inner().unwrap().finish().unwrap();
+
+        smol::run(async {
+            let (first, second) = futures::future::join(
+                DDragonClient::from_dragontail(&archive_path, LanguageCode::UNITED_STATES),
+                DDragonClient::from_dragontail(&archive_path, LanguageCode::UNITED_STATES),
+            )
+            .await;
+            let (mut client, _) = (first.unwrap(), second.unwrap());
+            let wukong = client.get_champion("MonkeyKing").await.unwrap();
+            assert_eq!(wukong.name, "Wukong");
+        });
+        assert!(dir.join("dragontail-10.10.1").is_dir());
+        let leftovers = std::fs::read_dir(&dir)
+            .unwrap()
+            .filter(|entry| {
+                entry
+                    .as_ref()
+                    .unwrap()
+                    .file_name()
+                    .to_string_lossy()
+                    .ends_with(".partial")
+            })
+            .count();
+        assert_eq!(leftovers, 0);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn gets_full_champion_data() {
+        smol::run(async {
+            let mut client = DDragonClient::new(LanguageCode::UNITED_STATES)
+                .await
+                .unwrap();
+            let xayah = client.get_champion("Xayah").await.unwrap();
+            assert_eq!(xayah.name, "Xayah");
+        })
+    }
+}
@@ -0,0 +1,524 @@
+//! Structured differences between two ddragon versions.
+//!
+//! [`PatchDiff`] compares champion base stats, spell cooldowns, costs and effects as well as
+//! item stats and costs. It can be serialized with serde or rendered as Markdown.
+//!
+//! # Example
+//! ```no_run
+//! use narwhalol::ddragon::DDragonClient;
+//! use narwhalol::LanguageCode;
+//!
+//! smol::run(async {
+//!     let ddragon = DDragonClient::new(LanguageCode::UNITED_STATES).await.unwrap();
+//!     let diff = ddragon.get_patch_diff("10.9.1", "10.10.1").await.unwrap();
+//!     println!("{}", diff.to_markdown());
+//! })
+//! ```
+//!
+//! [`PatchDiff`]: struct.PatchDiff.html
+use crate::dto::ddragon::{
+    AllItems, ChampionFullData, ChampionSpellData, ChampionStatsData, ItemData, ItemStatsData,
+};
+
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+
+/// Spell slot names in the order ddragon lists champion spells.
+const SPELL_SLOTS: [&str; 4] = ["Q", "W", "E", "R"];
+
+/// Static data of a single version that [`PatchDiff`] compares.
+///
+/// [`PatchDiff`]: struct.PatchDiff.html
+#[derive(Debug, Clone)]
+pub struct PatchData {
+    pub version: String,
+    /// Full champion data keyed by champion id
+    pub champions: HashMap<String, ChampionFullData>,
+    pub items: AllItems,
+}
+
+/// A single change between two versions.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Change {
+    ChampionAdded {
+        champion: String,
+    },
+    ChampionRemoved {
+        champion: String,
+    },
+    /// Base stat change, `stat` is the ddragon name such as `hpperlevel`
+    ChampionStat {
+        champion: String,
+        stat: String,
+        old: f64,
+        new: f64,
+    },
+    SpellCooldown {
+        champion: String,
+        slot: String,
+        spell: String,
+        old: Vec<f64>,
+        new: Vec<f64>,
+    },
+    SpellCost {
+        champion: String,
+        slot: String,
+        spell: String,
+        old: Vec<f64>,
+        new: Vec<f64>,
+    },
+    /// Change of `effect[index]`, the values behind the `{{ eN }}` tooltip placeholders
+    SpellEffect {
+        champion: String,
+        slot: String,
+        spell: String,
+        index: usize,
+        old: Option<Vec<f64>>,
+        new: Option<Vec<f64>>,
+    },
+    ItemAdded {
+        item_id: String,
+        item: String,
+    },
+    ItemRemoved {
+        item_id: String,
+        item: String,
+    },
+    /// Item stat change, `stat` is the ddragon name such as `FlatPhysicalDamageMod`
+    ItemStat {
+        item_id: String,
+        item: String,
+        stat: String,
+        old: f64,
+        new: f64,
+    },
+    ItemCost {
+        item_id: String,
+        item: String,
+        old: i64,
+        new: i64,
+    },
+}
+
+impl Change {
+    /// Champion or item name the change belongs to.
+    pub fn subject(&self) -> &str {
+        use self::Change::*;
+        match self {
+            ChampionAdded { champion }
+            | ChampionRemoved { champion }
+            | ChampionStat { champion, .. }
+            | SpellCooldown { champion, .. }
+            | SpellCost { champion, .. }
+            | SpellEffect { champion, .. } => champion,
+            ItemAdded { item, .. }
+            | ItemRemoved { item, .. }
+            | ItemStat { item, .. }
+            | ItemCost { item, .. } => item,
+        }
+    }
+
+    /// Whether the change is about an item rather than a champion.
+    pub fn is_item(&self) -> bool {
+        use self::Change::*;
+        matches!(
+            self,
+            ItemAdded { .. } | ItemRemoved { .. } | ItemStat { .. } | ItemCost { .. }
+        )
+    }
+
+    /// One line human readable description without the subject.
+    pub fn description(&self) -> String {
+        use self::Change::*;
+        match self {
+            ChampionAdded { .. } | ItemAdded { .. } => "added".to_owned(),
+            ChampionRemoved { .. } | ItemRemoved { .. } => "removed".to_owned(),
+            ChampionStat { stat, old, new, .. } | ItemStat { stat, old, new, .. } => {
+                format!("{}: {} → {}", stat, old, new)
+            }
+            SpellCooldown {
+                slot,
+                spell,
+                old,
+                new,
+                ..
+            } => format!("{} {} cooldown: {} → {}", slot, spell, join(old), join(new)),
+            SpellCost {
+                slot,
+                spell,
+                old,
+                new,
+                ..
+            } => format!("{} {} cost: {} → {}", slot, spell, join(old), join(new)),
+            SpellEffect {
+                slot,
+                spell,
+                index,
+                old,
+                new,
+                ..
+            } => format!(
+                "{} {} e{}: {} → {}",
+                slot,
+                spell,
+                index,
+                old.as_ref()
+                    .map(|v| join(v))
+                    .unwrap_or_else(|| "-".to_owned()),
+                new.as_ref()
+                    .map(|v| join(v))
+                    .unwrap_or_else(|| "-".to_owned())
+            ),
+            ItemCost { old, new, .. } => format!("total cost: {} → {}", old, new),
+        }
+    }
+}
+
+/// All changes between two versions.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PatchDiff {
+    pub from: String,
+    pub to: String,
+    pub changes: Vec<Change>,
+}
+
+impl PatchDiff {
+    /// Compares two versions of static data.
+    pub fn new(old: &PatchData, new: &PatchData) -> PatchDiff {
+        let mut changes = Vec::new();
+        diff_champions(&old.champions, &new.champions, &mut changes);
+        diff_items(&old.items.data, &new.items.data, &mut changes);
+        PatchDiff {
+            from: old.version.clone(),
+            to: new.version.clone(),
+            changes,
+        }
+    }
+
+    /// Whether both versions are identical in the compared fields.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Serializes the diff to pretty printed json.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Renders the diff as a Markdown document grouped by champion and item.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "# Patch {} → {}", self.from, self.to).unwrap();
+        for (title, items) in &[("Champions", false), ("Items", true)] {
+            let changes: Vec<&Change> = self
+                .changes
+                .iter()
+                .filter(|c| c.is_item() == *items)
+                .collect();
+            if changes.is_empty() {
+                continue;
+            }
+            writeln!(out, "\n## {}", title).unwrap();
+            let mut subject = None;
+            for change in changes {
+                if subject != Some(change.subject()) {
+                    subject = Some(change.subject());
+                    writeln!(out, "\n### {}\n", change.subject()).unwrap();
+                }
+                writeln!(out, "- {}", change.description()).unwrap();
+            }
+        }
+        out
+    }
+}
+
+fn join(values: &[f64]) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn diff_champions(
+    old: &HashMap<String, ChampionFullData>,
+    new: &HashMap<String, ChampionFullData>,
+    changes: &mut Vec<Change>,
+) {
+    let ids: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    for id in ids {
+        match (old.get(id), new.get(id)) {
+            (None, Some(champion)) => changes.push(Change::ChampionAdded {
+                champion: champion.name.clone(),
+            }),
+            (Some(champion), None) => changes.push(Change::ChampionRemoved {
+                champion: champion.name.clone(),
+            }),
+            (Some(old), Some(new)) => diff_champion(old, new, changes),
+            (None, None) => unreachable!(),
+        }
+    }
+}
+
+fn diff_champion(old: &ChampionFullData, new: &ChampionFullData, changes: &mut Vec<Change>) {
+    for ((stat, old_value), (_, new_value)) in champion_stats(&old.stats)
+        .into_iter()
+        .zip(champion_stats(&new.stats))
+    {
+        if old_value != new_value {
+            changes.push(Change::ChampionStat {
+                champion: new.name.clone(),
+                stat: stat.to_owned(),
+                old: old_value,
+                new: new_value,
+            });
+        }
+    }
+
+    for (slot, (old_spell, new_spell)) in SPELL_SLOTS
+        .iter()
+        .zip(old.spells.iter().zip(new.spells.iter()))
+    {
+        diff_spell(&new.name, slot, old_spell, new_spell, changes);
+    }
+}
+
+fn diff_spell(
+    champion: &str,
+    slot: &str,
+    old: &ChampionSpellData,
+    new: &ChampionSpellData,
+    changes: &mut Vec<Change>,
+) {
+    if old.cooldown != new.cooldown {
+        changes.push(Change::SpellCooldown {
+            champion: champion.to_owned(),
+            slot: slot.to_owned(),
+            spell: new.name.clone(),
+            old: old.cooldown.clone(),
+            new: new.cooldown.clone(),
+        });
+    }
+    if old.cost != new.cost {
+        changes.push(Change::SpellCost {
+            champion: champion.to_owned(),
+            slot: slot.to_owned(),
+            spell: new.name.clone(),
+            old: old.cost.clone(),
+            new: new.cost.clone(),
+        });
+    }
+    let effects = old.effect.len().max(new.effect.len());
+    for index in 1..effects {
+        let old_effect = old.effect.get(index).cloned().unwrap_or_default();
+        let new_effect = new.effect.get(index).cloned().unwrap_or_default();
+        if old_effect != new_effect {
+            changes.push(Change::SpellEffect {
+                champion: champion.to_owned(),
+                slot: slot.to_owned(),
+                spell: new.name.clone(),
+                index,
+                old: old_effect,
+                new: new_effect,
+            });
+        }
+    }
+}
+
+fn diff_items(
+    old: &HashMap<String, ItemData>,
+    new: &HashMap<String, ItemData>,
+    changes: &mut Vec<Change>,
+) {
+    let ids: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    for id in ids {
+        match (old.get(id), new.get(id)) {
+            (None, Some(item)) => changes.push(Change::ItemAdded {
+                item_id: id.clone(),
+                item: item.name.clone(),
+            }),
+            (Some(item), None) => changes.push(Change::ItemRemoved {
+                item_id: id.clone(),
+                item: item.name.clone(),
+            }),
+            (Some(old), Some(new)) => {
+                if old.gold.total != new.gold.total {
+                    changes.push(Change::ItemCost {
+                        item_id: id.clone(),
+                        item: new.name.clone(),
+                        old: old.gold.total,
+                        new: new.gold.total,
+                    });
+                }
+                for ((stat, old_value), (_, new_value)) in item_stats(&old.stats)
+                    .into_iter()
+                    .zip(item_stats(&new.stats))
+                {
+                    if old_value != new_value {
+                        changes.push(Change::ItemStat {
+                            item_id: id.clone(),
+                            item: new.name.clone(),
+                            stat: stat.to_owned(),
+                            old: old_value,
+                            new: new_value,
+                        });
+                    }
+                }
+            }
+            (None, None) => unreachable!(),
+        }
+    }
+}
+
+/// Champion base stats paired with their ddragon names.
+fn champion_stats(stats: &ChampionStatsData) -> Vec<(&'static str, f64)> {
+    vec![
+        ("hp", stats.hp),
+        ("hpperlevel", stats.hpperlevel),
+        ("mp", stats.mp),
+        ("mpperlevel", stats.mpperlevel),
+        ("movespeed", stats.movespeed),
+        ("armor", stats.armor),
+        ("armorperlevel", stats.armorperlevel),
+        ("spellblock", stats.spellblock),
+        ("spellblockperlevel", stats.spellblockperlevel),
+        ("attackrange", stats.attackrange),
+        ("hpregen", stats.hpregen),
+        ("hpregenperlevel", stats.hpregenperlevel),
+        ("mpregen", stats.mpregen),
+        ("mpregenperlevel", stats.mpregenperlevel),
+        ("crit", stats.crit),
+        ("critperlevel", stats.critperlevel),
+        ("attackdamage", stats.attackdamage),
+        ("attackdamageperlevel", stats.attackdamageperlevel),
+        ("attackspeedperlevel", stats.attackspeedperlevel),
+        ("attackspeed", stats.attackspeed),
+    ]
+}
+
+/// Item stats paired with their ddragon names, missing stats count as `0`.
+fn item_stats(stats: &ItemStatsData) -> Vec<(&'static str, f64)> {
+    let stats = vec![
+        ("FlatHPPoolMod", stats.flat_hp_pool_mod),
+        ("PercentHPPoolMod", stats.percent_hp_pool_mod),
+        ("FlatMPPoolMod", stats.flat_mp_pool_mod),
+        ("PercentMPPoolMod", stats.percent_mp_pool_mod),
+        ("FlatHPRegenMod", stats.flat_hp_regen_mod),
+        ("PercentHPRegenMod", stats.percent_hp_regen_mod),
+        ("FlatMPRegenMod", stats.flat_mp_regen_mod),
+        ("PercentMPRegenMod", stats.percent_mp_regen_mod),
+        ("FlatArmorMod", stats.flat_armor_mod),
+        ("PercentArmorMod", stats.percent_armor_mod),
+        ("FlatSpellBlockMod", stats.flat_spell_block_mod),
+        ("PercentSpellBlockMod", stats.percent_spell_block_mod),
+        ("FlatPhysicalDamageMod", stats.flat_physical_damage_mod),
+        (
+            "PercentPhysicalDamageMod",
+            stats.percent_physical_damage_mod,
+        ),
+        ("FlatMagicDamageMod", stats.flat_magic_damage_mod),
+        ("PercentMagicDamageMod", stats.percent_magic_damage_mod),
+        ("FlatMovementSpeedMod", stats.flat_movement_speed_mod),
+        ("PercentMovementSpeedMod", stats.percent_movement_speed_mod),
+        ("PercentAttackSpeedMod", stats.percent_attack_speed_mod),
+        ("FlatCritChanceMod", stats.flat_crit_chance_mod),
+        ("PercentLifeStealMod", stats.percent_life_steal_mod),
+    ];
+    stats
+        .into_iter()
+        .map(|(name, value)| (name, value.unwrap_or(0.0)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Change, PatchData, PatchDiff};
+    use crate::dto::ddragon::{AllItems, ChampionExtended};
+    use std::collections::HashMap;
+
+    fn patch_data() -> PatchData {
+        let mut champions = HashMap::new();
+        for file in &[
+            include_str!("../../tests/data/dragontail/10.10.1/data/en_US/champion/Annie.json"),
+            include_str!("../../tests/data/dragontail/10.10.1/data/en_US/champion/LeeSin.json"),
+        ] {
+            let extended: ChampionExtended = serde_json::from_str(file).unwrap();
+            champions.extend(extended.data);
+        }
+        let items: AllItems = serde_json::from_str(include_str!(
+            "../../tests/data/dragontail/10.10.1/data/en_US/item.json"
+        ))
+        .unwrap();
+        PatchData {
+            version: "10.10.1".to_owned(),
+            champions,
+            items,
+        }
+    }
+
+    #[test]
+    fn same_data_has_no_changes() {
+        let data = patch_data();
+        assert!(PatchDiff::new(&data, &data).is_empty());
+    }
+
+    #[test]
+    fn finds_champion_and_item_changes() {
+        let old = patch_data();
+        let mut new = patch_data();
+        new.version = "10.11.1".to_owned();
+        let lee_sin = new.champions.get_mut("LeeSin").unwrap();
+        lee_sin.stats.hp = 580.0;
+        lee_sin.spells[0].cooldown = vec![10.0, 9.0, 8.0, 7.0, 6.0];
+        new.champions.remove("Annie");
+        let infinity_edge = new.items.data.get_mut("3031").unwrap();
+        infinity_edge.gold.total = 3300;
+        infinity_edge.stats.flat_physical_damage_mod = Some(70.0);
+
+        let diff = PatchDiff::new(&old, &new);
+        assert_eq!(
+            diff.changes,
+            vec![
+                Change::ChampionRemoved {
+                    champion: "Annie".to_owned()
+                },
+                Change::ChampionStat {
+                    champion: "Lee Sin".to_owned(),
+                    stat: "hp".to_owned(),
+                    old: 575.0,
+                    new: 580.0
+                },
+                Change::SpellCooldown {
+                    champion: "Lee Sin".to_owned(),
+                    slot: "Q".to_owned(),
+                    spell: "Sonic Wave / Resonating Strike".to_owned(),
+                    old: vec![11.0, 10.0, 9.0, 8.0, 7.0],
+                    new: vec![10.0, 9.0, 8.0, 7.0, 6.0]
+                },
+                Change::ItemCost {
+                    item_id: "3031".to_owned(),
+                    item: "Infinity Edge".to_owned(),
+                    old: 3400,
+                    new: 3300
+                },
+                Change::ItemStat {
+                    item_id: "3031".to_owned(),
+                    item: "Infinity Edge".to_owned(),
+                    stat: "FlatPhysicalDamageMod".to_owned(),
+                    old: 80.0,
+                    new: 70.0
+                },
+            ]
+        );
+
+        let markdown = diff.to_markdown();
+        assert!(markdown.starts_with("# Patch 10.10.1 → 10.11.1\n"));
+        assert!(markdown.contains("### Lee Sin\n\n- hp: 575 → 580\n- Q Sonic Wave / Resonating Strike cooldown: 11/10/9/8/7 → 10/9/8/7/6\n"));
+        assert!(markdown.contains("## Items\n\n### Infinity Edge\n\n- total cost: 3400 → 3300\n"));
+
+        let json: serde_json::Value = serde_json::from_str(&diff.to_json()).unwrap();
+        assert_eq!(json["changes"][0]["kind"], "champion_removed");
+    }
+}
@@ -0,0 +1,134 @@
+//! Catalogue of all champion skins with their image urls.
+//!
+//! Skin ids as found in spectator data (e.g. `64011`) are `champion key * 1000 + skin num`.
+//! [`SkinCatalogue`] resolves them to the champion wearing the skin and to the splash art,
+//! loading screen and tile images on the ddragon cdn.
+//!
+//! [`SkinCatalogue`]: struct.SkinCatalogue.html
+use crate::dto::ddragon::ChampionFullData;
+
+use std::collections::HashMap;
+
+const IMG_URL: &str = "https://ddragon.leagueoflegends.com/cdn/img/champion";
+
+/// A single champion skin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Skin {
+    /// Skin id, e.g. `64011`
+    pub id: i64,
+    /// Skin number within the champion, e.g. `11`
+    pub num: i32,
+    /// Skin name, ddragon calls every base skin `default`
+    pub name: String,
+    /// Ddragon id of the champion, e.g. `LeeSin`
+    pub champion_id: String,
+    /// Numeric champion key, e.g. `64`
+    pub champion_key: i64,
+    pub champion_name: String,
+    /// Whether the skin has chromas
+    pub chromas: bool,
+    pub splash_url: String,
+    pub loading_url: String,
+    pub tile_url: String,
+}
+
+impl Skin {
+    /// Whether this is the default skin of its champion.
+    pub fn is_base(&self) -> bool {
+        self.num == 0
+    }
+}
+
+/// Lookup of skins by id, built from full champion data.
+#[derive(Debug, Clone)]
+pub struct SkinCatalogue {
+    skins: Vec<Skin>,
+    by_id: HashMap<i64, usize>,
+}
+
+impl SkinCatalogue {
+    /// Builds the catalogue from champion data as returned by [`DDragonClient::get_champion`].
+    ///
+    /// [`DDragonClient::get_champion`]: ../struct.DDragonClient.html#method.get_champion
+    pub fn new<'a, I: IntoIterator<Item = &'a ChampionFullData>>(champions: I) -> SkinCatalogue {
+        let mut skins = Vec::new();
+        for champion in champions {
+            let champion_key = champion.key.parse().unwrap_or_default();
+            for skin in &champion.skins {
+                let image = format!("{}_{}.jpg", champion.id, skin.num);
+                skins.push(Skin {
+                    id: skin.id.parse().unwrap_or_default(),
+                    num: skin.num,
+                    name: skin.name.clone(),
+                    champion_id: champion.id.clone(),
+                    champion_key,
+                    champion_name: champion.name.clone(),
+                    chromas: skin.chromas,
+                    splash_url: format!("{}/splash/{}", IMG_URL, image),
+                    loading_url: format!("{}/loading/{}", IMG_URL, image),
+                    tile_url: format!("{}/tiles/{}", IMG_URL, image),
+                });
+            }
+        }
+        skins.sort_by_key(|skin| skin.id);
+
+        let by_id = skins
+            .iter()
+            .enumerate()
+            .map(|(i, skin)| (skin.id, i))
+            .collect();
+        SkinCatalogue { skins, by_id }
+    }
+
+    /// All skins sorted by id.
+    pub fn skins(&self) -> &[Skin] {
+        &self.skins
+    }
+
+    /// Finds a skin by id, e.g. `64011` for God Fist Lee Sin.
+    pub fn by_id(&self, id: i64) -> Option<&Skin> {
+        self.by_id.get(&id).map(|&i| &self.skins[i])
+    }
+
+    /// Skins of a champion by ddragon id, e.g. `LeeSin`.
+    pub fn for_champion<'a>(&'a self, champion_id: &'a str) -> impl Iterator<Item = &'a Skin> {
+        self.skins
+            .iter()
+            .filter(move |skin| skin.champion_id == champion_id)
+    }
+
+    /// Skins that have (`true`) or do not have (`false`) chromas.
+    pub fn with_chromas(&self, chromas: bool) -> impl Iterator<Item = &Skin> {
+        self.skins
+            .iter()
+            .filter(move |skin| skin.chromas == chromas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SkinCatalogue;
+    use crate::dto::ddragon::ChampionExtended;
+
+    #[test]
+    fn resolves_skin_ids() {
+        let mut lee_sin: ChampionExtended = serde_json::from_str(include_str!(
+            "../../tests/data/dragontail/10.10.1/data/en_US/champion/LeeSin.json"
+        ))
+        .unwrap();
+        let lee_sin = lee_sin.data.remove("LeeSin").unwrap();
+        let catalogue = SkinCatalogue::new(vec![&lee_sin]);
+
+        let god_fist = catalogue.by_id(64011).unwrap();
+        assert_eq!(god_fist.name, "God Fist Lee Sin");
+        assert_eq!(god_fist.champion_key, 64);
+        assert_eq!(
+            god_fist.splash_url,
+            "https://ddragon.leagueoflegends.com/cdn/img/champion/splash/LeeSin_11.jpg"
+        );
+        assert!(catalogue.by_id(64002).is_none());
+        assert!(catalogue.by_id(64000).unwrap().is_base());
+        assert_eq!(catalogue.with_chromas(true).count(), 1);
+        assert_eq!(catalogue.for_champion("LeeSin").count(), 3);
+    }
+}
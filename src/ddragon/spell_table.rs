@@ -0,0 +1,102 @@
+//! Per-rank value tables of champion spells.
+//!
+//! ddragon stores spell values column-wise: `cooldown`, `cost` and every `effect[n]` list one value
+//! per rank, while `vars` hold the scaling ratios. [`scaling_table`] turns them into one
+//! [`SpellRankValues`] row per rank.
+//!
+//! [`scaling_table`]: fn.scaling_table.html
+//! [`SpellRankValues`]: struct.SpellRankValues.html
+use crate::dto::ddragon::ChampionSpellData;
+
+/// Values of a spell at a single rank.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpellRankValues {
+    /// 1-based spell rank
+    pub rank: usize,
+    /// Value of `{{ e1 }}`, the base damage of most damaging spells
+    pub base_damage: Option<f64>,
+    /// Values of all `{{ eN }}` placeholders, `effects[n]` being `eN` and `effects[0]` always `None`
+    pub effects: Vec<Option<f64>>,
+    /// Sum of all `spelldamage` ratios
+    pub ap_ratio: f64,
+    /// Sum of all `attackdamage` ratios
+    pub ad_ratio: f64,
+    /// Sum of all `bonusattackdamage` ratios
+    pub bonus_ad_ratio: f64,
+    /// Every ratio as `(link, coeff)`, e.g. `("bonushealth", 0.1)`
+    pub ratios: Vec<(String, f64)>,
+    pub cooldown: Option<f64>,
+    pub cost: Option<f64>,
+}
+
+/// Builds one row per rank up to the spell's `maxrank`.
+pub fn scaling_table(spell: &ChampionSpellData) -> Vec<SpellRankValues> {
+    (1..=spell.maxrank.max(1) as usize)
+        .map(|rank| {
+            let at_rank = |values: &[f64]| values.get(rank - 1).copied();
+            let effects: Vec<Option<f64>> = spell
+                .effect
+                .iter()
+                .map(|effect| effect.as_ref().and_then(|values| at_rank(values)))
+                .collect();
+            let ratios: Vec<(String, f64)> = spell
+                .vars
+                .iter()
+                .filter_map(|var| Some((var.link.clone(), var.coeff.at_rank(rank)?)))
+                .collect();
+            let ratio = |link: &str| {
+                ratios
+                    .iter()
+                    .filter(|(l, _)| l == link)
+                    .map(|(_, coeff)| coeff)
+                    .sum()
+            };
+
+            SpellRankValues {
+                rank,
+                base_damage: effects.get(1).copied().flatten(),
+                ap_ratio: ratio("spelldamage"),
+                ad_ratio: ratio("attackdamage"),
+                bonus_ad_ratio: ratio("bonusattackdamage"),
+                cooldown: at_rank(&spell.cooldown),
+                cost: at_rank(&spell.cost),
+                effects,
+                ratios,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scaling_table;
+    use crate::dto::ddragon::{ChampionExtended, SpellValue};
+
+    #[test]
+    fn builds_rows_per_rank() {
+        let mut annie: ChampionExtended = serde_json::from_str(include_str!(
+            "../../tests/data/dragontail/10.10.1/data/en_US/champion/Annie.json"
+        ))
+        .unwrap();
+        let annie = annie.data.remove("Annie").unwrap();
+        assert_eq!(annie.spells[0].vars[0].key, "a1");
+        assert_eq!(annie.spells[0].vars[0].coeff, SpellValue::Single(0.8));
+
+        let table = scaling_table(&annie.spells[0]);
+        assert_eq!(table.len(), 5);
+        assert_eq!(table[1].rank, 2);
+        assert_eq!(table[1].base_damage, Some(115.0));
+        assert_eq!(table[1].effects[2], Some(50.0));
+        assert_eq!(table[1].ap_ratio, 0.8);
+        assert_eq!(table[1].ad_ratio, 0.0);
+        assert_eq!(table[4].cost, Some(80.0));
+        assert_eq!(table[4].cooldown, Some(4.0));
+    }
+
+    #[test]
+    fn per_rank_coefficients_use_last_value_past_the_end() {
+        let coeff: SpellValue = serde_json::from_str("[0.5, 0.6]").unwrap();
+        assert_eq!(coeff.at_rank(1), Some(0.5));
+        assert_eq!(coeff.at_rank(3), Some(0.6));
+    }
+}
@@ -0,0 +1,281 @@
+//! Champion stat calculation per level and with items.
+//!
+//! Stats grow with Riot's per-level formula
+//! `base + growth * (level - 1) * (0.7025 + 0.0175 * (level - 1))`,
+//! except attack speed, whose growth is a percentage bonus on top of the base attack speed.
+//!
+//! # Example
+//! ```no_run
+//! use narwhalol::ddragon::{DDragonClient, StatCalculator};
+//! use narwhalol::LanguageCode;
+//!
+//! smol::run(async {
+//!     let mut ddragon = DDragonClient::new(LanguageCode::UNITED_STATES).await.unwrap();
+//!     let lee_sin = ddragon.get_champion("LeeSin").await.unwrap();
+//!     let items = ddragon.get_items().await.unwrap();
+//!     let stats = StatCalculator::new(&lee_sin.stats)
+//!         .level(11)
+//!         .item(&items.data["3031"])
+//!         .calculate();
+//!     println!("{:?}", stats);
+//! })
+//! ```
+use crate::dto::ddragon::{ChampionStatsData, ItemData, ItemStatsData};
+
+/// Minimal champion level.
+pub const MIN_LEVEL: u8 = 1;
+/// Maximal champion level.
+pub const MAX_LEVEL: u8 = 18;
+
+/// Final champion stats produced by [`StatCalculator`].
+///
+/// Regeneration values are per 5 seconds, crit chance and life steal are fractions.
+///
+/// [`StatCalculator`]: struct.StatCalculator.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChampionStats {
+    pub level: u8,
+    pub hp: f64,
+    pub mp: f64,
+    pub hp_regen: f64,
+    pub mp_regen: f64,
+    pub armor: f64,
+    pub magic_resist: f64,
+    pub attack_damage: f64,
+    pub ability_power: f64,
+    pub attack_speed: f64,
+    pub attack_range: f64,
+    pub move_speed: f64,
+    pub crit_chance: f64,
+    pub life_steal: f64,
+}
+
+/// Calculates [`ChampionStats`] from [`ChampionStatsData`] for a level and a set of items.
+///
+/// [`ChampionStats`]: struct.ChampionStats.html
+/// [`ChampionStatsData`]: ../../dto/ddragon/struct.ChampionStatsData.html
+#[derive(Debug, Clone)]
+pub struct StatCalculator {
+    base: ChampionStatsData,
+    level: u8,
+    bonus: ItemStatsData,
+}
+
+impl StatCalculator {
+    /// Creates a calculator for a level 1 champion without items.
+    pub fn new(stats: &ChampionStatsData) -> StatCalculator {
+        StatCalculator {
+            base: stats.clone(),
+            level: MIN_LEVEL,
+            bonus: ItemStatsData::default(),
+        }
+    }
+
+    /// Sets champion level, clamped to `1..=18`.
+    pub fn level(self, level: u8) -> Self {
+        StatCalculator {
+            level: level.clamp(MIN_LEVEL, MAX_LEVEL),
+            ..self
+        }
+    }
+
+    /// Adds the stats of an item.
+    pub fn item(self, item: &ItemData) -> Self {
+        self.stats(&item.stats)
+    }
+
+    /// Adds the stats of several items.
+    pub fn items<'a, I: IntoIterator<Item = &'a ItemData>>(self, items: I) -> Self {
+        items.into_iter().fold(self, |calc, item| calc.item(item))
+    }
+
+    /// Adds raw bonus stats, e.g. from runes or buffs.
+    pub fn stats(mut self, stats: &ItemStatsData) -> Self {
+        let bonus = &mut self.bonus;
+        add(&mut bonus.flat_hp_pool_mod, stats.flat_hp_pool_mod);
+        add(&mut bonus.percent_hp_pool_mod, stats.percent_hp_pool_mod);
+        add(&mut bonus.flat_mp_pool_mod, stats.flat_mp_pool_mod);
+        add(&mut bonus.percent_mp_pool_mod, stats.percent_mp_pool_mod);
+        add(&mut bonus.flat_hp_regen_mod, stats.flat_hp_regen_mod);
+        add(&mut bonus.percent_hp_regen_mod, stats.percent_hp_regen_mod);
+        add(&mut bonus.flat_mp_regen_mod, stats.flat_mp_regen_mod);
+        add(&mut bonus.percent_mp_regen_mod, stats.percent_mp_regen_mod);
+        add(&mut bonus.flat_armor_mod, stats.flat_armor_mod);
+        add(&mut bonus.percent_armor_mod, stats.percent_armor_mod);
+        add(&mut bonus.flat_spell_block_mod, stats.flat_spell_block_mod);
+        add(
+            &mut bonus.percent_spell_block_mod,
+            stats.percent_spell_block_mod,
+        );
+        add(
+            &mut bonus.flat_physical_damage_mod,
+            stats.flat_physical_damage_mod,
+        );
+        add(
+            &mut bonus.percent_physical_damage_mod,
+            stats.percent_physical_damage_mod,
+        );
+        add(
+            &mut bonus.flat_magic_damage_mod,
+            stats.flat_magic_damage_mod,
+        );
+        add(
+            &mut bonus.percent_magic_damage_mod,
+            stats.percent_magic_damage_mod,
+        );
+        add(
+            &mut bonus.flat_movement_speed_mod,
+            stats.flat_movement_speed_mod,
+        );
+        add(
+            &mut bonus.percent_movement_speed_mod,
+            stats.percent_movement_speed_mod,
+        );
+        add(
+            &mut bonus.percent_attack_speed_mod,
+            stats.percent_attack_speed_mod,
+        );
+        add(&mut bonus.flat_crit_chance_mod, stats.flat_crit_chance_mod);
+        add(
+            &mut bonus.percent_life_steal_mod,
+            stats.percent_life_steal_mod,
+        );
+        self
+    }
+
+    /// Calculates the final stats.
+    pub fn calculate(&self) -> ChampionStats {
+        let base = &self.base;
+        let bonus = &self.bonus;
+        let stat = |value: Option<f64>| value.unwrap_or(0.0);
+        let grow = |value: f64, per_level: f64| value + per_level * growth_multiplier(self.level);
+        let total = |value: f64, flat: f64, percent: f64| (value + flat) * (1.0 + percent);
+
+        let attack_speed_bonus = base.attackspeedperlevel / 100.0 * growth_multiplier(self.level)
+            + stat(bonus.percent_attack_speed_mod);
+
+        ChampionStats {
+            level: self.level,
+            hp: total(
+                grow(base.hp, base.hpperlevel),
+                stat(bonus.flat_hp_pool_mod),
+                stat(bonus.percent_hp_pool_mod),
+            ),
+            mp: total(
+                grow(base.mp, base.mpperlevel),
+                stat(bonus.flat_mp_pool_mod),
+                stat(bonus.percent_mp_pool_mod),
+            ),
+            hp_regen: total(
+                grow(base.hpregen, base.hpregenperlevel),
+                stat(bonus.flat_hp_regen_mod),
+                stat(bonus.percent_hp_regen_mod),
+            ),
+            mp_regen: total(
+                grow(base.mpregen, base.mpregenperlevel),
+                stat(bonus.flat_mp_regen_mod),
+                stat(bonus.percent_mp_regen_mod),
+            ),
+            armor: total(
+                grow(base.armor, base.armorperlevel),
+                stat(bonus.flat_armor_mod),
+                stat(bonus.percent_armor_mod),
+            ),
+            magic_resist: total(
+                grow(base.spellblock, base.spellblockperlevel),
+                stat(bonus.flat_spell_block_mod),
+                stat(bonus.percent_spell_block_mod),
+            ),
+            attack_damage: total(
+                grow(base.attackdamage, base.attackdamageperlevel),
+                stat(bonus.flat_physical_damage_mod),
+                stat(bonus.percent_physical_damage_mod),
+            ),
+            ability_power: total(
+                0.0,
+                stat(bonus.flat_magic_damage_mod),
+                stat(bonus.percent_magic_damage_mod),
+            ),
+            attack_speed: base.attackspeed * (1.0 + attack_speed_bonus),
+            attack_range: base.attackrange,
+            move_speed: total(
+                base.movespeed,
+                stat(bonus.flat_movement_speed_mod),
+                stat(bonus.percent_movement_speed_mod),
+            ),
+            crit_chance: (grow(base.crit, base.critperlevel) + stat(bonus.flat_crit_chance_mod))
+                .min(1.0),
+            life_steal: stat(bonus.percent_life_steal_mod),
+        }
+    }
+}
+
+/// Adds an item stat to a bonus total.
+fn add(total: &mut Option<f64>, value: Option<f64>) {
+    if let Some(value) = value {
+        *total = Some(total.unwrap_or(0.0) + value);
+    }
+}
+
+/// Riot's per-level growth multiplier: `0` at level 1 and `17` at level 18.
+pub fn growth_multiplier(level: u8) -> f64 {
+    let n = f64::from(level.clamp(MIN_LEVEL, MAX_LEVEL) - 1);
+    n * (0.7025 + 0.0175 * n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{growth_multiplier, StatCalculator};
+    use crate::dto::ddragon::{AllItems, ChampionExtended};
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn grows_stats_per_level() {
+        let mut lee_sin: ChampionExtended = serde_json::from_str(include_str!(
+            "../../tests/data/dragontail/10.10.1/data/en_US/champion/LeeSin.json"
+        ))
+        .unwrap();
+        let lee_sin = lee_sin.data.remove("LeeSin").unwrap();
+
+        assert!(approx(growth_multiplier(1), 0.0));
+        assert!(approx(growth_multiplier(18), 17.0));
+
+        let level_one = StatCalculator::new(&lee_sin.stats).calculate();
+        assert!(approx(level_one.hp, 575.0));
+        assert!(approx(level_one.attack_speed, 0.651));
+
+        let level_max = StatCalculator::new(&lee_sin.stats).level(25).calculate();
+        assert_eq!(level_max.level, 18);
+        assert!(approx(level_max.hp, 575.0 + 85.0 * 17.0));
+        assert!(approx(level_max.attack_speed, 0.651 * 1.51));
+        assert!(approx(level_max.attack_range, 125.0));
+    }
+
+    #[test]
+    fn applies_item_stats() {
+        let mut lee_sin: ChampionExtended = serde_json::from_str(include_str!(
+            "../../tests/data/dragontail/10.10.1/data/en_US/champion/LeeSin.json"
+        ))
+        .unwrap();
+        let lee_sin = lee_sin.data.remove("LeeSin").unwrap();
+        let items: AllItems = serde_json::from_str(include_str!(
+            "../../tests/data/dragontail/10.10.1/data/en_US/item.json"
+        ))
+        .unwrap();
+
+        let stats = StatCalculator::new(&lee_sin.stats)
+            .items(vec![
+                &items.data["3031"],
+                &items.data["1042"],
+                &items.data["1001"],
+            ])
+            .calculate();
+        assert!(approx(stats.attack_damage, 150.0));
+        assert!(approx(stats.crit_chance, 0.2));
+        assert!(approx(stats.attack_speed, 0.651 * 1.12));
+        assert!(approx(stats.move_speed, 370.0));
+    }
+}
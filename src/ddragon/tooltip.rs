@@ -0,0 +1,350 @@
+//! Rendering of spell and passive tooltips.
+//!
+//! Spell tooltips in ddragon are templates with placeholders like `{{ e1 }}` (effect values),
+//! `{{ a1 }}` (scaling ratios), `{{ cost }}` and custom markup tags such as `<magicDamage>`.
+//! [`SpellTooltip`] substitutes the values of a given spell rank and produces either plain text or HTML.
+//!
+//! Substituted values are HTML-escaped in HTML output. Placeholders that can not be resolved are
+//! marked as `[name?]` in plain text and as `<span class="unknown-placeholder">name</span>` in
+//! HTML, and are listed in
+//! [`RenderedTooltip::unknown_placeholders`].
+//!
+//! [`SpellTooltip`]: struct.SpellTooltip.html
+//! [`RenderedTooltip::unknown_placeholders`]: struct.RenderedTooltip.html#structfield.unknown_placeholders
+use crate::dto::ddragon::{ChampionPassiveData, ChampionSpellData};
+
+/// Output format of a rendered tooltip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TooltipFormat {
+    /// Markup is stripped and `<br>` tags are turned into newlines.
+    PlainText,
+    /// Standard HTML tags are kept, custom tags become `<span class="tag">`.
+    Html,
+}
+
+/// Result of rendering a tooltip.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedTooltip {
+    pub text: String,
+    /// Names of placeholders that could not be substituted, in order of appearance.
+    pub unknown_placeholders: Vec<String>,
+}
+
+/// Tooltip renderer for a [`ChampionSpellData`] at a given rank.
+///
+/// [`ChampionSpellData`]: ../../dto/ddragon/struct.ChampionSpellData.html
+#[derive(Debug, Clone)]
+pub struct SpellTooltip<'a> {
+    spell: &'a ChampionSpellData,
+    rank: usize,
+    resource_name: Option<String>,
+}
+
+impl<'a> SpellTooltip<'a> {
+    /// Creates a renderer for rank 1 of `spell`.
+    pub fn new(spell: &'a ChampionSpellData) -> SpellTooltip<'a> {
+        SpellTooltip {
+            spell,
+            rank: 1,
+            resource_name: None,
+        }
+    }
+
+    /// Sets spell rank, clamped to `1..=maxrank`.
+    pub fn rank(self, rank: usize) -> Self {
+        let max_rank = self.spell.maxrank.max(1) as usize;
+        SpellTooltip {
+            rank: rank.clamp(1, max_rank),
+            ..self
+        }
+    }
+
+    /// Sets the value of `{{ abilityresourcename }}`, usually the champion's `partype` like `Mana`.
+    pub fn resource_name(self, name: &str) -> Self {
+        SpellTooltip {
+            resource_name: Some(name.to_owned()),
+            ..self
+        }
+    }
+
+    /// Renders the spell tooltip.
+    pub fn render(&self, format: TooltipFormat) -> RenderedTooltip {
+        render_template(&self.spell.tooltip, format, |name| self.placeholder(name))
+    }
+
+    /// Renders the spell cost, e.g. `60 Mana`.
+    pub fn render_cost(&self, format: TooltipFormat) -> RenderedTooltip {
+        let template = if self.spell.cost_type.contains("{{ cost }}") {
+            self.spell.cost_type.clone()
+        } else {
+            format!("{{{{ cost }}}}{}", self.spell.cost_type)
+        };
+        render_template(&template, format, |name| self.placeholder(name))
+    }
+
+    /// Renders the level up tooltip lines, e.g. `Damage: 80 -> 115`.
+    pub fn render_level_tip(&self, format: TooltipFormat) -> Vec<RenderedTooltip> {
+        let leveltip = &self.spell.leveltip;
+        leveltip
+            .label
+            .iter()
+            .zip(leveltip.effect.iter())
+            .map(|(label, effect)| {
+                let template = format!("{}: {}", label, effect);
+                render_template(&template, format, |name| self.placeholder(name))
+            })
+            .collect()
+    }
+
+    fn placeholder(&self, name: &str) -> Option<String> {
+        // `NL` suffix means "value on the next level" and is used in level up tips
+        let (name, rank) = match name.strip_suffix("NL") {
+            Some(stripped) => (
+                stripped,
+                (self.rank + 1).min(self.spell.maxrank.max(1) as usize),
+            ),
+            None => (name, self.rank),
+        };
+        let at_rank = |values: &[f64]| values.get(rank - 1).map(|v| format_number(*v));
+
+        match name {
+            "cost" => at_rank(&self.spell.cost),
+            "cooldown" => at_rank(&self.spell.cooldown),
+            "range" => at_rank(
+                &self
+                    .spell
+                    .range
+                    .iter()
+                    .map(|&r| r as f64)
+                    .collect::<Vec<_>>(),
+            ),
+            "maxammo" => Some(self.spell.maxammo.clone()),
+            "abilityresourcename" => self.resource_name.clone(),
+            "spellmodifierdescriptionappend" => Some(String::new()),
+            _ => {
+                if let Some(n) = name.strip_prefix('e').and_then(|n| n.parse::<usize>().ok()) {
+                    return self
+                        .spell
+                        .effect
+                        .get(n)
+                        .and_then(|effect| effect.as_ref())
+                        .and_then(|effect| at_rank(effect));
+                }
+                self.var(name, rank)
+            }
+        }
+    }
+
+    fn var(&self, name: &str, rank: usize) -> Option<String> {
+        let var = self.spell.vars.iter().find(|var| var.key == name)?;
+        let coeff = var.coeff.at_rank(rank)?;
+        Some(format!(
+            "{}% {}",
+            format_number(coeff * 100.0),
+            scaling_label(&var.link)
+        ))
+    }
+}
+
+/// Renders the description of a champion passive.
+pub fn render_passive(passive: &ChampionPassiveData, format: TooltipFormat) -> RenderedTooltip {
+    render_template(&passive.description, format, |_| None)
+}
+
+/// Human readable name of a `vars` link, e.g. `bonusattackdamage` is `bonus AD`.
+fn scaling_label(link: &str) -> &str {
+    match link {
+        "spelldamage" => "AP",
+        "attackdamage" => "AD",
+        "bonusattackdamage" => "bonus AD",
+        "armor" => "Armor",
+        "bonusarmor" => "bonus Armor",
+        "spellblock" => "MR",
+        "bonusspellblock" => "bonus MR",
+        "health" => "max Health",
+        "bonushealth" => "bonus Health",
+        "mana" => "max Mana",
+        link => link,
+    }
+}
+
+/// Formats numbers the way ddragon does: `80` instead of `80.0`, at most two decimals otherwise.
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        let formatted = format!("{:.2}", value);
+        formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_owned()
+    }
+}
+
+/// Tags that are valid HTML and are kept as is in [`TooltipFormat::Html`].
+const HTML_TAGS: &[&str] = &[
+    "span", "br", "font", "b", "i", "u", "em", "strong", "li", "ul", "p",
+];
+
+fn render_template<F>(template: &str, format: TooltipFormat, lookup: F) -> RenderedTooltip
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut text = String::with_capacity(template.len());
+    let mut unknown_placeholders = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find(&['{', '<'][..]) {
+        text.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if rest.starts_with("{{") {
+            let end = match rest.find("}}") {
+                Some(end) => end,
+                None => break,
+            };
+            let name = rest[2..end].trim();
+            match (lookup(name), format) {
+                (Some(value), TooltipFormat::PlainText) => text.push_str(&value),
+                (Some(value), TooltipFormat::Html) => text.push_str(&escape_html(&value)),
+                (None, _) => {
+                    match format {
+                        TooltipFormat::PlainText => text.push_str(&format!("[{}?]", name)),
+                        TooltipFormat::Html => text.push_str(&format!(
+                            "<span class=\"unknown-placeholder\">{}</span>",
+                            escape_html(name)
+                        )),
+                    }
+                    unknown_placeholders.push(name.to_owned());
+                }
+            }
+            rest = &rest[end + 2..];
+        } else if rest.starts_with('<') {
+            let end = match rest.find('>') {
+                Some(end) => end,
+                None => break,
+            };
+            push_tag(&mut text, &rest[1..end], format);
+            rest = &rest[end + 1..];
+        } else {
+            text.push_str(&rest[..1]);
+            rest = &rest[1..];
+        }
+    }
+    text.push_str(rest);
+
+    RenderedTooltip {
+        text,
+        unknown_placeholders,
+    }
+}
+
+fn push_tag(text: &mut String, tag: &str, format: TooltipFormat) {
+    let closing = tag.starts_with('/');
+    let name = tag
+        .trim_start_matches('/')
+        .trim_end_matches('/')
+        .split_whitespace()
+        .next()
+        .unwrap_or_default();
+
+    match format {
+        TooltipFormat::PlainText => {
+            if name.eq_ignore_ascii_case("br") {
+                text.push('\n');
+            }
+        }
+        TooltipFormat::Html => {
+            if HTML_TAGS.iter().any(|t| t.eq_ignore_ascii_case(name)) {
+                text.push('<');
+                text.push_str(tag);
+                text.push('>');
+            } else if closing {
+                text.push_str("</span>");
+            } else {
+                text.push_str(&format!("<span class=\"{}\">", escape_html(name)));
+            }
+        }
+    }
+}
+
+/// Escapes text inserted into [`TooltipFormat::Html`] output.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_passive, SpellTooltip, TooltipFormat};
+    use crate::dto::ddragon::{ChampionExtended, ChampionFullData};
+
+    fn annie() -> ChampionFullData {
+        let mut annie: ChampionExtended = serde_json::from_str(include_str!(
+            "../../tests/data/dragontail/10.10.1/data/en_US/champion/Annie.json"
+        ))
+        .unwrap();
+        annie.data.remove("Annie").unwrap()
+    }
+
+    #[test]
+    fn renders_plain_text_for_rank() {
+        let annie = annie();
+        let tooltip = SpellTooltip::new(&annie.spells[0])
+            .rank(2)
+            .render(TooltipFormat::PlainText);
+        assert_eq!(
+            tooltip.text,
+            "Annie hurls a fireball, dealing 115 (+80% AP) magic damage. \
+             If the target dies, Annie is refunded the Mana cost and 50% of the cooldown."
+        );
+        assert!(tooltip.unknown_placeholders.is_empty());
+
+        let cost = SpellTooltip::new(&annie.spells[0])
+            .rank(3)
+            .resource_name(&annie.partype)
+            .render_cost(TooltipFormat::PlainText);
+        assert_eq!(cost.text, "70 Mana");
+
+        let level_tip =
+            SpellTooltip::new(&annie.spells[2]).render_level_tip(TooltipFormat::PlainText);
+        assert_eq!(level_tip[1].text, "Cooldown: 14 -> 13");
+    }
+
+    #[test]
+    fn renders_html_and_marks_unknown_placeholders() {
+        let annie = annie();
+        let tooltip = SpellTooltip::new(&annie.spells[3])
+            .rank(10)
+            .render(TooltipFormat::Html);
+        assert_eq!(
+            tooltip.text,
+            "Tibbers appears, dealing 400 <span class=\"color99FF99\">(+75% AP)</span> magic damage."
+        );
+        assert!(tooltip.unknown_placeholders.is_empty());
+
+        let mut spell = annie.spells[3].clone();
+        spell.tooltip = "Deals {{ e1 }} {{ <b>&unknown }} damage.".to_owned();
+        let tooltip = SpellTooltip::new(&spell).render(TooltipFormat::Html);
+        assert_eq!(
+            tooltip.text,
+            "Deals 150 <span class=\"unknown-placeholder\">&lt;b&gt;&amp;unknown</span> damage."
+        );
+        assert_eq!(tooltip.unknown_placeholders, vec!["<b>&unknown"]);
+        let tooltip = SpellTooltip::new(&spell).render(TooltipFormat::PlainText);
+        assert_eq!(tooltip.text, "Deals 150 [<b>&unknown?] damage.");
+
+        let passive = render_passive(&annie.passive, TooltipFormat::PlainText);
+        assert_eq!(passive.text, annie.passive.description);
+    }
+}
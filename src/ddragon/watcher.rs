@@ -0,0 +1,210 @@
+//! Polling of ddragon `versions.json` for new patches.
+//!
+//! [`VersionWatcher`] checks the version list on an interval and yields a [`VersionEvent`] whenever
+//! the newest version changes. Given a [`DDragonClient`], it also switches that client to the new
+//! version and evicts the old version's data from its cache, so long running services pick up new
+//! champions without a restart.
+//! The version is shared between clones, so the caller's handle and the client embedded in a
+//! `LeagueClient` are switched as well.
+//!
+//! # Example
+//! ```no_run
+//! use futures::prelude::*;
+//! use narwhalol::ddragon::{DDragonClient, VersionWatcher};
+//! use narwhalol::LanguageCode;
+//! use std::time::Duration;
+//!
+//! smol::run(async {
+//!     let ddragon = DDragonClient::new(LanguageCode::UNITED_STATES).await.unwrap();
+//!     let mut events = VersionWatcher::new(&ddragon.version(), Duration::from_secs(600))
+//!         .client(ddragon)
+//!         .into_stream();
+//!     while let Some(event) = events.next().await {
+//!         let event = event.unwrap();
+//!         println!("{} -> {}", event.previous, event.current);
+//!     }
+//! })
+//! ```
+//!
+//! [`VersionWatcher`]: struct.VersionWatcher.html
+//! [`VersionEvent`]: struct.VersionEvent.html
+//! [`DDragonClient`]: ../struct.DDragonClient.html
+use crate::ddragon::DDragonClient;
+use crate::error::ClientError;
+use crate::types::Client;
+use crate::utils::{construct_hyper_client, delay, get_json, DDRAGON_VERSIONS_URL};
+
+use futures::prelude::*;
+use futures::stream::BoxStream;
+use hyper::Uri;
+use log::debug;
+use std::time::Duration;
+
+/// A new ddragon version was released.
+#[derive(Debug, Clone)]
+pub struct VersionEvent {
+    pub previous: String,
+    pub current: String,
+    /// The watched client, already switched to `current`, if the watcher has one.
+    pub client: Option<DDragonClient>,
+}
+
+/// Polls ddragon for new versions, see the [module docs](index.html).
+#[derive(Debug, Clone)]
+pub struct VersionWatcher {
+    http: Client,
+    versions_url: Uri,
+    interval: Duration,
+    current: String,
+    ddragon: Option<DDragonClient>,
+}
+
+impl VersionWatcher {
+    /// Creates a watcher reporting versions other than `current`, checking every `interval`.
+    pub fn new(current: &str, interval: Duration) -> VersionWatcher {
+        VersionWatcher {
+            http: construct_hyper_client(),
+            versions_url: DDRAGON_VERSIONS_URL.parse().unwrap(),
+            interval,
+            current: current.to_owned(),
+            ddragon: None,
+        }
+    }
+
+    /// Switches `ddragon` and all its clones to every new version, see
+    /// [`DDragonClient::set_version`].
+    ///
+    /// [`DDragonClient::set_version`]: ../struct.DDragonClient.html#method.set_version
+    ///
+    /// The watcher starts from the client's version.
+    pub fn client(self, ddragon: DDragonClient) -> Self {
+        VersionWatcher {
+            current: ddragon.version(),
+            ddragon: Some(ddragon),
+            ..self
+        }
+    }
+
+    /// Polls `url` instead of ddragon's `versions.json`, e.g. a local mirror.
+    pub fn versions_url(self, url: Uri) -> Self {
+        VersionWatcher {
+            versions_url: url,
+            ..self
+        }
+    }
+
+    /// Latest version seen by the watcher.
+    pub fn current(&self) -> &str {
+        &self.current
+    }
+
+    /// Checks the version list once, returning an event if the newest version changed.
+    pub async fn check(&mut self) -> Result<Option<VersionEvent>, ClientError> {
+        let versions: Vec<String> = get_json(self.http.clone(), self.versions_url.clone()).await?;
+        let latest = match versions.into_iter().next() {
+            Some(latest) if latest != self.current => latest,
+            _ => return Ok(None),
+        };
+        debug!("New ddragon version {} (was {})", latest, self.current);
+
+        let previous = std::mem::replace(&mut self.current, latest.clone());
+        let client = self.ddragon.as_mut().map(|ddragon| {
+            ddragon.set_version(&latest);
+            ddragon.clone()
+        });
+        Ok(Some(VersionEvent {
+            previous,
+            current: latest,
+            client,
+        }))
+    }
+
+    /// Turns the watcher into a stream of new version events.
+    ///
+    /// The first check happens immediately, later ones after every `interval`.
+    /// Failed checks are yielded as errors and polling continues afterwards.
+    pub fn into_stream(self) -> BoxStream<'static, Result<VersionEvent, ClientError>> {
+        stream::unfold((self, false), |(mut watcher, wait)| async move {
+            if wait {
+                delay(watcher.interval).await;
+            }
+            loop {
+                match watcher.check().await {
+                    Ok(None) => delay(watcher.interval).await,
+                    Ok(Some(event)) => return Some((Ok(event), (watcher, true))),
+                    Err(e) => return Some((Err(e), (watcher, true))),
+                }
+            }
+        })
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VersionWatcher;
+    use crate::constants::LanguageCode;
+    use crate::ddragon::DDragonClient;
+    use futures::prelude::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::Duration;
+
+    /// Serves `versions.json`, announcing a new patch from the third request on.
+    fn serve_versions() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            for (i, stream) in listener.incoming().enumerate() {
+                let mut stream = stream.unwrap();
+                let mut request_line = String::new();
+                BufReader::new(&stream)
+                    .read_line(&mut request_line)
+                    .unwrap();
+                let body = if i < 2 {
+                    r#"["10.10.1","10.9.1"]"#
+                } else {
+                    r#"["10.11.1","10.10.1","10.9.1"]"#
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        format!("http://{}/api/versions.json", addr)
+    }
+
+    #[test]
+    fn emits_new_versions_and_updates_client() {
+        let url = serve_versions();
+        smol::run(async {
+            let mut ddragon = DDragonClient::from_dragontail(
+                "tests/data/dragontail",
+                LanguageCode::UNITED_STATES,
+            )
+            .await
+            .unwrap();
+            ddragon.get_champions().await.unwrap();
+
+            let mut watcher = VersionWatcher::new("", Duration::from_millis(10))
+                .client(ddragon.clone())
+                .versions_url(url.parse().unwrap());
+            assert!(watcher.check().await.unwrap().is_none());
+
+            let event = watcher.into_stream().next().await.unwrap().unwrap();
+            assert_eq!(event.previous, "10.10.1");
+            assert_eq!(event.current, "10.11.1");
+            assert_eq!(event.client.unwrap().version(), "10.11.1");
+            assert!(ddragon.cache.lock().is_empty());
+            // The caller's handle follows the watched clone
+            assert_eq!(ddragon.version(), "10.11.1");
+            assert!(ddragon.base_url().contains("/10.11.1/"));
+            assert_eq!(ddragon.lang(LanguageCode::KOREA).version(), "10.11.1");
+        })
+    }
+}
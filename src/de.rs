@@ -0,0 +1,350 @@
+//! Response deserialization that reports the path of mismatching fields.
+//!
+//! Responses are first deserialized directly. Only if that fails they are parsed into a
+//! [`serde_json::Value`] and deserialized again through [`Node`], which remembers the path of the
+//! innermost value that failed, e.g. `data.Annie.spells[0].maxammo`.
+//!
+//! In lenient mode the failing field or element is then removed from the response and
+//! deserialization is retried, so `Option` and `#[serde(default)]` fields fall back to their
+//! defaults with a warning. Fields that can not be defaulted make their parent fail in turn, up to
+//! the nearest element of a map or sequence, which is dropped on its own, e.g. `spells[0]` rather
+//! than all spells or the whole champion.
+use crate::error::{ClientError, DeserializeError, RequestInfo};
+
+use log::warn;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+use serde_json::{Error, Value};
+use snafu::ResultExt;
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Deserializes a response `body` to `request`.
+///
+/// In lenient mode `body` is replaced with the repaired response if any field had to be removed,
+/// so it can be cached without repeating the warnings.
+pub(crate) fn from_response<T: DeserializeOwned>(
+    body: &mut String,
+    request: &RequestInfo,
+    lenient: bool,
+) -> Result<T, ClientError> {
+    if let Ok(parsed) = serde_json::from_str(body) {
+        return Ok(parsed);
+    }
+    let mut value: Value = serde_json::from_str(body).context(DeserializeError {
+        request: Box::new(request.clone()),
+        path: Path::default().to_string(),
+    })?;
+
+    let mut repaired = false;
+    loop {
+        let (path, error) = match from_value(&value) {
+            Ok(parsed) => {
+                if repaired {
+                    *body = value.to_string();
+                }
+                return Ok(parsed);
+            }
+            Err(failure) => failure,
+        };
+        if !lenient || !path.remove_from(&mut value) {
+            return Err(error).context(DeserializeError {
+                request: Box::new(request.clone()),
+                path: path.to_string(),
+            });
+        }
+        warn!("Ignoring {} in response to {}: {}", path, request, error);
+        repaired = true;
+    }
+}
+
+/// Deserializes `value`, returning the path of the innermost failing value on error.
+fn from_value<T: DeserializeOwned>(value: &Value) -> Result<T, (Path, Error)> {
+    let failed = RefCell::new(None);
+    deserialize_node(PhantomData::<T>, value, &Segment::Root, &failed)
+        .map_err(|error| (failed.into_inner().unwrap_or_default(), error))
+}
+
+/// Location of a value inside a response, e.g. `data.Annie.spells[0]`.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Path(Vec<PathPart>);
+
+#[derive(Debug, Clone, PartialEq)]
+enum PathPart {
+    Key(String),
+    Index(usize),
+}
+
+impl Path {
+    /// Removes the value at the path from its parent object or array, returns false if the path
+    /// is the response itself.
+    fn remove_from(&self, value: &mut Value) -> bool {
+        let (last, parents) = match self.0.split_last() {
+            Some(split) => split,
+            None => return false,
+        };
+        let mut parent = value;
+        for part in parents {
+            parent = match (part, parent) {
+                (PathPart::Key(key), Value::Object(map)) => map.get_mut(key).unwrap(),
+                (PathPart::Index(i), Value::Array(values)) => &mut values[*i],
+                _ => return false,
+            };
+        }
+        match (last, parent) {
+            (PathPart::Key(key), Value::Object(map)) => map.remove(key).is_some(),
+            (PathPart::Index(i), Value::Array(values)) if *i < values.len() => {
+                values.remove(*i);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str(".");
+        }
+        for (i, part) in self.0.iter().enumerate() {
+            match part {
+                PathPart::Key(key) if i == 0 => f.write_str(key)?,
+                PathPart::Key(key) => write!(f, ".{}", key)?,
+                PathPart::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Path of the value being deserialized, linked to its parent's segment.
+enum Segment<'a> {
+    Root,
+    Key(&'a Segment<'a>, &'a str),
+    Index(&'a Segment<'a>, usize),
+}
+
+impl Segment<'_> {
+    fn to_path(&self) -> Path {
+        let mut parts = Vec::new();
+        let mut segment = self;
+        loop {
+            segment = match segment {
+                Segment::Root => break,
+                Segment::Key(parent, key) => {
+                    parts.push(PathPart::Key((*key).to_owned()));
+                    parent
+                }
+                Segment::Index(parent, index) => {
+                    parts.push(PathPart::Index(*index));
+                    parent
+                }
+            };
+        }
+        parts.reverse();
+        Path(parts)
+    }
+}
+
+/// Deserializes a single value, recording its path if it is the first one to fail.
+// `Result::inspect_err` needs Rust 1.76
+#[allow(clippy::manual_inspect)]
+fn deserialize_node<'de, S: DeserializeSeed<'de>>(
+    seed: S,
+    value: &Value,
+    segment: &Segment,
+    failed: &RefCell<Option<Path>>,
+) -> Result<S::Value, Error> {
+    seed.deserialize(Node {
+        value,
+        segment,
+        failed,
+    })
+    .map_err(|error| {
+        failed.borrow_mut().get_or_insert_with(|| segment.to_path());
+        error
+    })
+}
+
+struct Node<'a> {
+    value: &'a Value,
+    segment: &'a Segment<'a>,
+    failed: &'a RefCell<Option<Path>>,
+}
+
+impl<'de> de::Deserializer<'de> for Node<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(b) => visitor.visit_bool(*b),
+            Value::Number(n) => match (n.as_u64(), n.as_i64()) {
+                (Some(u), _) => visitor.visit_u64(u),
+                (None, Some(i)) => visitor.visit_i64(i),
+                (None, None) => visitor.visit_f64(n.as_f64().unwrap_or_default()),
+            },
+            Value::String(s) => visitor.visit_str(s),
+            Value::Array(values) => visitor.visit_seq(SeqNodes {
+                values: values.iter().enumerate(),
+                segment: self.segment,
+                failed: self.failed,
+            }),
+            Value::Object(map) => visitor.visit_map(MapNodes {
+                entries: map.iter(),
+                pending: None,
+                segment: self.segment,
+                failed: self.failed,
+            }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_enum(self.value.clone(), name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+struct SeqNodes<'a> {
+    values: std::iter::Enumerate<std::slice::Iter<'a, Value>>,
+    segment: &'a Segment<'a>,
+    failed: &'a RefCell<Option<Path>>,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqNodes<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.values.next() {
+            Some((index, value)) => {
+                let segment = Segment::Index(self.segment, index);
+                deserialize_node(seed, value, &segment, self.failed).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+struct MapNodes<'a> {
+    entries: serde_json::map::Iter<'a>,
+    pending: Option<(&'a String, &'a Value)>,
+    segment: &'a Segment<'a>,
+    failed: &'a RefCell<Option<Path>>,
+}
+
+impl<'de> de::MapAccess<'de> for MapNodes<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.pending = Some((key, value));
+                seed.deserialize(key.as_str().into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let (key, value) = self
+            .pending
+            .take()
+            .ok_or_else(|| de::Error::custom("value requested before key"))?;
+        let segment = Segment::Key(self.segment, key);
+        deserialize_node(seed, value, &segment, self.failed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_response;
+    use crate::dto::ddragon::ChampionExtended;
+    use crate::error::{ClientError, RequestInfo};
+    use serde_json::Value;
+
+    fn annie_with(field: &str, value: Value) -> String {
+        let mut annie: Value = serde_json::from_str(include_str!(
+            "../tests/data/dragontail/10.10.1/data/en_US/champion/Annie.json"
+        ))
+        .unwrap();
+        annie["data"]["Annie"]["spells"][0][field] = value;
+        annie.to_string()
+    }
+
+    #[test]
+    fn reports_the_path_of_mismatching_fields() {
+        let request = RequestInfo::get("http://localhost/Annie.json".parse().unwrap(), "ddragon");
+        let mut body = annie_with("maxammo", Value::Null);
+        match from_response::<ChampionExtended>(&mut body, &request, false) {
+            Err(ClientError::DeserializeError { path, .. }) => {
+                assert_eq!(path, "data.Annie.spells[0].maxammo")
+            }
+            other => panic!("expected a deserialize error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn lenient_mode_defaults_mismatching_fields() {
+        let request = RequestInfo::get("http://localhost/Annie.json".parse().unwrap(), "ddragon");
+        let mut body = annie_with("maxammo", Value::Bool(true));
+        let annie: ChampionExtended = from_response(&mut body, &request, true).unwrap();
+        assert_eq!(annie.data["Annie"].spells[0].maxammo, "");
+        assert!(!body.contains("\"maxammo\":true"));
+
+        let mut body = annie_with("name", Value::Null);
+        let annie: ChampionExtended = from_response(&mut body, &request, true).unwrap();
+        let spells = &annie.data["Annie"].spells;
+        assert_eq!(spells.len(), 3);
+        assert_eq!(spells[0].id, "AnnieW");
+
+        let mut annie: Value = serde_json::from_str(&annie_with("name", Value::Null)).unwrap();
+        annie["data"]["Annie"]["stats"]["hp"] = Value::Null;
+        let mut body = annie.to_string();
+        let annie: ChampionExtended = from_response(&mut body, &request, true).unwrap();
+        assert!(annie.data.is_empty());
+    }
+}
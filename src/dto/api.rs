@@ -1,53 +1,188 @@
-use serde::Deserialize;
+use crate::constants::{Champion, Division, MaybeKnown, RankedQueue, RankedTier};
+use crate::dto::epoch_millis;
+use crate::dto::ids::{AccountId, Puuid, SummonerId};
+use crate::dto::ExtraFields;
+use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Summoner {
     pub profile_icon_id: i32,
     pub name: String,
-    pub puuid: String,
+    pub puuid: Puuid,
     pub summoner_level: u64,
-    pub revision_date: u64,
-    pub id: String,
-    pub account_id: String,
+    /// Last time the summoner's name, level or icon changed
+    #[serde(with = "epoch_millis")]
+    pub revision_date: SystemTime,
+    pub id: SummonerId,
+    pub account_id: AccountId,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChampionInfo {
-    pub free_champion_ids: Vec<i64>,
-    pub free_champion_ids_for_new_players: Vec<i64>,
+    pub free_champion_ids: Vec<Champion>,
+    pub free_champion_ids_for_new_players: Vec<Champion>,
     pub max_new_player_level: i64,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChampionMastery {
     pub chest_granted: bool,
     pub champion_level: i32,
     pub champion_points: i32,
-    pub champion_id: i64,
+    pub champion_id: Champion,
     pub champion_points_until_next_level: i64,
-    pub last_play_time: i64,
+    #[serde(with = "epoch_millis")]
+    pub last_play_time: SystemTime,
     pub tokens_earned: i64,
     pub champion_points_since_last_level: i64,
-    pub summoner_id: String,
+    pub summoner_id: SummonerId,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LeagueInfo {
-    pub queue_type: String,
+    pub queue_type: MaybeKnown<RankedQueue>,
     pub summoner_name: String,
     pub hot_streak: bool,
     pub wins: i64,
     pub veteran: bool,
     pub losses: i64,
-    pub rank: String,
-    pub tier: String,
+    pub rank: MaybeKnown<Division>,
+    pub tier: MaybeKnown<RankedTier>,
     pub inactive: bool,
     pub fresh_blood: bool,
     pub league_id: String,
-    pub summoner_id: String,
+    pub summoner_id: SummonerId,
     pub league_points: i64,
+    /// Promotion series progress, only present while in promos
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mini_series: Option<MiniSeries>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Progress of a promotion series.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MiniSeries {
+    pub wins: i32,
+    pub losses: i32,
+    /// Wins needed to be promoted
+    pub target: i32,
+    /// One character per game, `W` for a win, `L` for a loss and `N` for a game not played yet
+    pub progress: String,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl MiniSeries {
+    /// Results of the series games, `None` for games not played yet.
+    pub fn games(&self) -> impl Iterator<Item = Option<bool>> + '_ {
+        self.progress.chars().map(|game| match game {
+            'W' => Some(true),
+            'L' => Some(false),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChampionMastery, LeagueInfo};
+    use crate::constants::{Champion, MaybeKnown, RankedQueue, RankedTier};
+    use crate::de;
+    use crate::error::{ClientError, RequestInfo};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn parses_league_info_with_promos_and_unknown_tier() {
+        let info: LeagueInfo = serde_json::from_str(
+            r#"{"queueType":"RANKED_SOLO_5x5","summonerName":"Vetro","hotStreak":false,
+                "wins":10,"veteran":false,"losses":8,"rank":"I","tier":"EMERALD","inactive":false,
+                "freshBlood":true,"leagueId":"id","summonerId":"id","leaguePoints":100,
+                "miniSeries":{"losses":1,"progress":"WLN","target":2,"wins":1}}"#,
+        )
+        .unwrap();
+        assert_eq!(info.queue_type, RankedQueue::SOLO);
+        assert_eq!(
+            info.tier,
+            MaybeKnown::<RankedTier>::Unknown("EMERALD".into())
+        );
+        let series = info.mini_series.unwrap();
+        assert_eq!(
+            series.games().collect::<Vec<_>>(),
+            vec![Some(true), Some(false), None]
+        );
+    }
+
+    #[test]
+    fn league_info_round_trips_with_unknown_fields() {
+        let json: serde_json::Value = serde_json::from_str(
+            r#"{"queueType":"RANKED_FLEX_SR","summonerName":"Vetro","hotStreak":false,
+                "wins":10,"veteran":false,"losses":8,"rank":"IV","tier":"EMERALD","inactive":false,
+                "freshBlood":true,"leagueId":"id","summonerId":"id","leaguePoints":0,
+                "summonerIconId":7}"#,
+        )
+        .unwrap();
+        let info: LeagueInfo = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(info.extra["summonerIconId"], 7);
+        assert_eq!(serde_json::to_value(&info).unwrap(), json);
+    }
+
+    #[test]
+    fn parses_mastery_timestamps() {
+        let mastery: ChampionMastery = serde_json::from_str(
+            r#"{"chestGranted":true,"championLevel":7,"championPoints":93748,"championId":64,
+                "championPointsUntilNextLevel":0,"lastPlayTime":1588000000123,"tokensEarned":0,
+                "championPointsSinceLastLevel":72148,"summonerId":"id"}"#,
+        )
+        .unwrap();
+        assert_eq!(mastery.champion_id, Champion::LEE_SIN);
+        assert_eq!(
+            mastery.last_play_time,
+            UNIX_EPOCH + Duration::from_millis(1_588_000_000_123)
+        );
+        let json = serde_json::to_value(&mastery).unwrap();
+        assert_eq!(json["lastPlayTime"], 1_588_000_000_123_i64);
+    }
+
+    #[test]
+    fn strict_mode_requires_mastery_points() {
+        let request = RequestInfo::get("http://localhost/masteries".parse().unwrap(), "mastery");
+        let mut body = r#"{"chestGranted":true,"championLevel":7,"championId":64,
+            "championPointsUntilNextLevel":0,"lastPlayTime":1588000000123,"tokensEarned":0,
+            "championPointsSinceLastLevel":72148,"summonerId":"id"}"#
+            .to_owned();
+        match de::from_response::<ChampionMastery>(&mut body, &request, false) {
+            Err(ClientError::DeserializeError { .. }) => (),
+            other => panic!("expected a deserialize error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn lenient_mode_drops_broken_league_entries() {
+        let request = RequestInfo::get("http://localhost/entries".parse().unwrap(), "league");
+        let mut body =
+            r#"[{"queueType":"RANKED_SOLO_5x5","summonerName":"Broken","hotStreak":false,
+            "wins":10,"veteran":false,"losses":8,"rank":"I","tier":"GOLD","inactive":false,
+            "freshBlood":true,"leagueId":"id","summonerId":"id","leaguePoints":"lots"},
+            {"queueType":"RANKED_FLEX_SR","summonerName":"Vetro","hotStreak":false,
+            "wins":10,"veteran":false,"losses":8,"rank":"I","tier":"GOLD","inactive":false,
+            "freshBlood":true,"leagueId":"id","summonerId":"id","leaguePoints":20}]"#
+                .to_owned();
+        assert!(de::from_response::<Vec<LeagueInfo>>(&mut body.clone(), &request, false).is_err());
+        let entries: Vec<LeagueInfo> = de::from_response(&mut body, &request, true).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].summoner_name, "Vetro");
+    }
 }
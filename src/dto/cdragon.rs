@@ -0,0 +1,137 @@
+use crate::dto::ExtraFields;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CDragonChampionSummary {
+    pub id: i64,
+    pub name: String,
+    pub alias: String,
+    pub square_portrait_path: String,
+    pub roles: Vec<String>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CDragonChampion {
+    pub id: i64,
+    pub name: String,
+    pub alias: String,
+    pub title: String,
+    #[serde(default)]
+    pub short_bio: String,
+    pub tactical_info: CDragonTacticalInfo,
+    pub square_portrait_path: String,
+    pub roles: Vec<String>,
+    pub skins: Vec<CDragonSkin>,
+    pub passive: CDragonPassive,
+    pub spells: Vec<CDragonSpell>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CDragonTacticalInfo {
+    pub style: i32,
+    pub difficulty: i32,
+    pub damage_type: String,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CDragonPassive {
+    pub name: String,
+    pub ability_icon_path: String,
+    pub description: String,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CDragonSpell {
+    pub spell_key: String,
+    pub name: String,
+    pub ability_icon_path: String,
+    pub cost: String,
+    pub cooldown: String,
+    pub description: String,
+    #[serde(default)]
+    pub dynamic_description: String,
+    #[serde(default)]
+    pub range: Vec<f64>,
+    #[serde(default)]
+    pub cost_coefficients: Vec<f64>,
+    #[serde(default)]
+    pub cooldown_coefficients: Vec<f64>,
+    #[serde(default)]
+    pub coefficients: HashMap<String, f64>,
+    #[serde(default)]
+    pub effect_amounts: HashMap<String, Vec<f64>>,
+    pub max_level: i32,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CDragonSkin {
+    pub id: i64,
+    pub is_base: bool,
+    pub name: String,
+    pub splash_path: String,
+    pub uncentered_splash_path: String,
+    pub tile_path: String,
+    pub load_screen_path: String,
+    pub skin_type: Option<String>,
+    pub rarity: String,
+    pub is_legacy: bool,
+    pub chroma_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chromas: Vec<CDragonChroma>,
+    pub skin_lines: Option<Vec<CDragonSkinLine>>,
+    pub description: Option<String>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CDragonChroma {
+    pub id: i64,
+    pub name: String,
+    pub chroma_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub colors: Vec<String>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CDragonSkinLine {
+    pub id: i64,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CDragonPerk {
+    pub id: i64,
+    pub name: String,
+    pub major_change_patch_version: String,
+    pub tooltip: String,
+    pub short_desc: String,
+    pub long_desc: String,
+    pub icon_path: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub end_of_game_stat_descs: Vec<String>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
@@ -1,48 +1,107 @@
-use serde::Deserialize;
+use crate::constants::{ChampionTag, ResourceType};
+use crate::dto::{number, ExtraFields};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AllChampions {
     #[serde(rename = "type")]
     pub data_type: String,
     pub format: String,
     pub version: String,
     pub data: HashMap<String, ChampionData>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+impl AllChampions {
+    /// Champions having `tag` among their tags.
+    pub fn with_tag<'a>(&'a self, tag: &'a ChampionTag) -> impl Iterator<Item = &'a ChampionData> {
+        self.data
+            .values()
+            .filter(move |champion| champion.has_tag(tag))
+    }
+
+    /// Champions using `resource`, e.g. all [`ResourceType::ENERGY`] users.
+    ///
+    /// [`ResourceType::ENERGY`]: ../../constants/resource_type/struct.ResourceType.html#associatedconstant.ENERGY
+    pub fn with_resource<'a>(
+        &'a self,
+        resource: &'a ResourceType,
+    ) -> impl Iterator<Item = &'a ChampionData> {
+        self.data
+            .values()
+            .filter(move |champion| champion.uses_resource(resource))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChampionExtended {
     #[serde(rename = "type")]
     pub type_field: String,
     pub format: String,
     pub version: String,
     pub data: HashMap<String, ChampionFullData>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChampionData {
     pub version: String,
     pub id: String,
     pub key: String,
     pub name: String,
     pub title: String,
+    #[serde(default)]
     pub blurb: String,
     pub info: ChampionInfoData,
     pub image: ChampionImageData,
     pub tags: Vec<String>,
     pub partype: String,
     pub stats: ChampionStatsData,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+impl ChampionData {
+    /// Tags known to [`ChampionTag`], unknown ones are skipped.
+    ///
+    /// [`ChampionTag`]: ../../constants/champion_tag/struct.ChampionTag.html
+    pub fn champion_tags(&self) -> Vec<ChampionTag> {
+        self.tags
+            .iter()
+            .filter_map(|tag| tag.parse().ok())
+            .collect()
+    }
+
+    pub fn has_tag(&self, tag: &ChampionTag) -> bool {
+        self.tags.iter().any(|t| tag == t.as_str())
+    }
+
+    /// Typed `partype`, `None` for resources unknown to [`ResourceType`].
+    ///
+    /// [`ResourceType`]: ../../constants/resource_type/struct.ResourceType.html
+    pub fn resource_type(&self) -> Option<ResourceType> {
+        self.partype.parse().ok()
+    }
+
+    pub fn uses_resource(&self, resource: &ResourceType) -> bool {
+        resource == self.partype.as_str()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChampionInfoData {
     pub attack: i32,
     pub defense: i32,
     pub magic: i32,
     pub difficulty: i32,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChampionImageData {
     pub full: String,
     pub sprite: String,
@@ -51,33 +110,57 @@ pub struct ChampionImageData {
     pub y: i32,
     pub w: i32,
     pub h: i32,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChampionStatsData {
+    #[serde(serialize_with = "number::serialize")]
     pub hp: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub hpperlevel: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub mp: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub mpperlevel: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub movespeed: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub armor: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub armorperlevel: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub spellblock: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub spellblockperlevel: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub attackrange: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub hpregen: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub hpregenperlevel: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub mpregen: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub mpregenperlevel: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub crit: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub critperlevel: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub attackdamage: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub attackdamageperlevel: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub attackspeedperlevel: f64,
+    #[serde(serialize_with = "number::serialize")]
     pub attackspeed: f64,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChampionFullData {
     pub id: String,
     pub key: String,
@@ -85,9 +168,13 @@ pub struct ChampionFullData {
     pub title: String,
     pub image: ChampionImageData,
     pub skins: Vec<ChampionSkinData>,
+    #[serde(default)]
     pub lore: String,
+    #[serde(default)]
     pub blurb: String,
+    #[serde(default)]
     pub allytips: Vec<String>,
+    #[serde(default)]
     pub enemytips: Vec<String>,
     pub tags: Vec<String>,
     pub partype: String,
@@ -95,99 +182,478 @@ pub struct ChampionFullData {
     pub stats: ChampionStatsData,
     pub spells: Vec<ChampionSpellData>,
     pub passive: ChampionPassiveData,
+    #[serde(default)]
     pub recommended: Vec<ChampionRecommendedData>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Full data of every champion keyed by ddragon id and numeric key.
+///
+/// Returned from [`DDragonClient::get_all_champions_full`].
+///
+/// [`DDragonClient::get_all_champions_full`]: ../../ddragon/struct.DDragonClient.html#method.get_all_champions_full
+#[derive(Debug, Clone)]
+pub struct FullChampions {
+    by_id: HashMap<String, ChampionFullData>,
+    id_by_key: HashMap<i64, String>,
+}
+
+impl FullChampions {
+    pub fn new<I: IntoIterator<Item = ChampionFullData>>(champions: I) -> FullChampions {
+        let mut by_id = HashMap::new();
+        let mut id_by_key = HashMap::new();
+        for champion in champions {
+            if let Ok(key) = champion.key.parse::<i64>() {
+                id_by_key.insert(key, champion.id.clone());
+            }
+            by_id.insert(champion.id.clone(), champion);
+        }
+        FullChampions { by_id, id_by_key }
+    }
+
+    /// Finds a champion by ddragon id, e.g. `"MonkeyKing"`.
+    pub fn by_id(&self, id: &str) -> Option<&ChampionFullData> {
+        self.by_id.get(id)
+    }
+
+    /// Finds a champion by numeric key, e.g. `64` for Lee Sin.
+    pub fn by_key(&self, key: i64) -> Option<&ChampionFullData> {
+        self.id_by_key.get(&key).and_then(|id| self.by_id.get(id))
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_id.is_empty()
+    }
+
+    /// Iterates over all champions in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = &ChampionFullData> {
+        self.by_id.values()
+    }
+
+    /// Consumes the collection, returning champions keyed by ddragon id.
+    pub fn into_map(self) -> HashMap<String, ChampionFullData> {
+        self.by_id
+    }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChampionSkinData {
     pub id: String,
     pub num: i32,
     pub name: String,
     pub chromas: bool,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ChampionSpellData {
     pub id: String,
     pub name: String,
     pub description: String,
+    #[serde(default)]
     pub tooltip: String,
+    #[serde(default)]
     pub leveltip: ChampionSpellLevelTipData,
     pub maxrank: i32,
+    #[serde(serialize_with = "number::serialize_seq")]
     pub cooldown: Vec<f64>,
+    #[serde(default)]
     pub cooldown_burn: String,
+    #[serde(serialize_with = "number::serialize_seq")]
     pub cost: Vec<f64>,
+    #[serde(default)]
     pub cost_burn: String,
+    #[serde(default)]
     pub datavalues: ChampionDataValues,
+    #[serde(default, serialize_with = "number::serialize_effects")]
     pub effect: Vec<Option<Vec<f64>>>,
+    #[serde(default)]
     pub effect_burn: Vec<Option<String>>,
-    pub vars: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub vars: Vec<ChampionSpellVar>,
+    #[serde(default)]
     pub cost_type: String,
+    #[serde(default)]
     pub maxammo: String,
+    #[serde(default)]
     pub range: Vec<i64>,
+    #[serde(default)]
     pub range_burn: String,
     pub image: ChampionImageData,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct ChampionSpellLevelTipData {
     pub label: Vec<String>,
     pub effect: Vec<String>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChampionPassiveData {
     pub name: String,
     pub description: String,
     pub image: ChampionImageData,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+/// Recommended item set of a champion.
+///
+/// Optional fields missing from a response are left out when serializing, the opaque
+/// `customPanel` is kept in `extra` as received.
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ChampionRecommendedData {
     pub champion: String,
+    #[serde(default)]
     pub title: String,
+    #[serde(default)]
     pub map: String,
+    #[serde(default)]
     pub mode: String,
-    #[serde(rename = "type")]
+    #[serde(default, rename = "type")]
     pub type_field: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sortrank: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub extension_page: Option<bool>,
-    pub custom_panel: Option<serde_json::Value>,
+    #[serde(default)]
     pub blocks: Vec<ChampionBlockData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub required_perk: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub use_obvious_checkmark: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<bool>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct ChampionDataValues {}
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ChampionDataValues {
+    #[serde(flatten)]
+    pub values: HashMap<String, SpellValue>,
+}
 
-#[derive(Debug, Deserialize, Clone)]
+/// Scaling ratio of a spell, referenced in tooltips as `{{ key }}`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChampionSpellVar {
+    /// Stat the spell scales with, e.g. `spelldamage` or `bonusattackdamage`
+    pub link: String,
+    pub coeff: SpellValue,
+    /// Placeholder name, e.g. `a1`
+    pub key: String,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A spell value that is either the same for every rank or given per rank.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum SpellValue {
+    Single(#[serde(serialize_with = "number::serialize")] f64),
+    PerRank(#[serde(serialize_with = "number::serialize_seq")] Vec<f64>),
+}
+
+impl SpellValue {
+    /// Value at a 1-based `rank`, ranks past the end of the list use the last value.
+    pub fn at_rank(&self, rank: usize) -> Option<f64> {
+        match self {
+            SpellValue::Single(value) => Some(*value),
+            SpellValue::PerRank(values) => values
+                .get(rank.max(1) - 1)
+                .or_else(|| values.last())
+                .copied(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ChampionBlockData {
     #[serde(rename = "type")]
     pub type_field: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rec_math: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rec_steps: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub min_summoner_level: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_summoner_level: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub show_if_summoner_spell: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hide_if_summoner_spell: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub append_after_section: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub visible_with_all_of: Vec<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub hidden_with_any_of: Vec<String>,
+    #[serde(default)]
     pub items: Vec<ChampionItemData>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChampionItemData {
     pub id: String,
     pub count: i64,
+    #[serde(rename = "hideCount", skip_serializing_if = "Option::is_none")]
     pub hide_count: Option<bool>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AllItems {
+    #[serde(rename = "type")]
+    pub data_type: String,
+    pub version: String,
+    pub data: HashMap<String, ItemData>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ItemData {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub colloq: String,
+    #[serde(default)]
+    pub plaintext: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub into: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub from: Vec<String>,
+    pub image: ChampionImageData,
+    pub gold: ItemGoldData,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub maps: HashMap<String, bool>,
+    #[serde(default)]
+    pub stats: ItemStatsData,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth: Option<i64>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ItemGoldData {
+    pub base: i64,
+    pub purchasable: bool,
+    pub total: i64,
+    pub sell: i64,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Stats granted by an item. Percent values are fractions, e.g. `0.12` for 12% attack speed.
+///
+/// Stats missing from the item are `None`, so they stay apart from stats given as an explicit `0`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ItemStatsData {
+    #[serde(rename = "FlatHPPoolMod", skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub flat_hp_pool_mod: Option<f64>,
+    #[serde(rename = "PercentHPPoolMod", skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub percent_hp_pool_mod: Option<f64>,
+    #[serde(rename = "FlatMPPoolMod", skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub flat_mp_pool_mod: Option<f64>,
+    #[serde(rename = "PercentMPPoolMod", skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub percent_mp_pool_mod: Option<f64>,
+    #[serde(rename = "FlatHPRegenMod", skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub flat_hp_regen_mod: Option<f64>,
+    #[serde(rename = "PercentHPRegenMod", skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub percent_hp_regen_mod: Option<f64>,
+    #[serde(rename = "FlatMPRegenMod", skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub flat_mp_regen_mod: Option<f64>,
+    #[serde(rename = "PercentMPRegenMod", skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub percent_mp_regen_mod: Option<f64>,
+    #[serde(rename = "FlatArmorMod", skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub flat_armor_mod: Option<f64>,
+    #[serde(rename = "PercentArmorMod", skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub percent_armor_mod: Option<f64>,
+    #[serde(rename = "FlatSpellBlockMod", skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub flat_spell_block_mod: Option<f64>,
+    #[serde(
+        rename = "PercentSpellBlockMod",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub percent_spell_block_mod: Option<f64>,
+    #[serde(
+        rename = "FlatPhysicalDamageMod",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub flat_physical_damage_mod: Option<f64>,
+    #[serde(
+        rename = "PercentPhysicalDamageMod",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub percent_physical_damage_mod: Option<f64>,
+    #[serde(rename = "FlatMagicDamageMod", skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub flat_magic_damage_mod: Option<f64>,
+    #[serde(
+        rename = "PercentMagicDamageMod",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub percent_magic_damage_mod: Option<f64>,
+    #[serde(
+        rename = "FlatMovementSpeedMod",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub flat_movement_speed_mod: Option<f64>,
+    #[serde(
+        rename = "PercentMovementSpeedMod",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub percent_movement_speed_mod: Option<f64>,
+    #[serde(
+        rename = "PercentAttackSpeedMod",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub percent_attack_speed_mod: Option<f64>,
+    #[serde(rename = "FlatCritChanceMod", skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub flat_crit_chance_mod: Option<f64>,
+    #[serde(
+        rename = "PercentLifeStealMod",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[serde(serialize_with = "number::serialize_option")]
+    pub percent_life_steal_mod: Option<f64>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Ddragon versions a region is currently running, from `realms/{region}.json`.
+///
+/// The opaque `store` entry is kept in `extra` as received, whether it is `null` or missing.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Realm {
+    /// Data version per data type, e.g. `"champion": "10.10.1"`
+    #[serde(rename = "n")]
+    pub versions: HashMap<String, String>,
+    /// Current realm version
+    #[serde(rename = "v")]
+    pub version: String,
+    /// Default language code of the region
+    #[serde(rename = "l")]
+    pub language: String,
+    pub cdn: String,
+    /// Ddragon data version
+    pub dd: String,
+    pub lg: String,
+    pub css: String,
+    pub profileiconmax: i64,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AllChampions, AllItems, ChampionExtended, ChampionRecommendedData, ItemStatsData, Realm,
+    };
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use serde_json::Value;
+
+    /// Asserts that `json` deserializes to `T` and serializes back to the exact same value.
+    fn assert_round_trips<T: Serialize + DeserializeOwned>(json: &str) {
+        let original: Value = serde_json::from_str(json).unwrap();
+        let parsed: T = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), original);
+    }
+
+    #[test]
+    fn champions_round_trip() {
+        assert_round_trips::<AllChampions>(include_str!(
+            "../../tests/data/dragontail/10.10.1/data/en_US/champion.json"
+        ));
+        assert_round_trips::<ChampionExtended>(include_str!(
+            "../../tests/data/dragontail/10.10.1/data/en_US/champion/Annie.json"
+        ));
+        assert_round_trips::<ChampionExtended>(include_str!(
+            "../../tests/data/dragontail/10.10.1/data/en_US/champion/LeeSin.json"
+        ));
+        assert_round_trips::<ChampionExtended>(include_str!(
+            "../../tests/data/dragontail/10.10.1/data/en_US/champion/MonkeyKing.json"
+        ));
+    }
+
+    #[test]
+    fn items_round_trip() {
+        assert_round_trips::<AllItems>(include_str!(
+            "../../tests/data/dragontail/10.10.1/data/en_US/item.json"
+        ));
+    }
+
+    #[test]
+    fn item_stats_keep_explicit_zeros() {
+        let json =
+            r#"{"FlatMovementSpeedMod":25,"PercentAttackSpeedMod":0,"FlatCritChanceMod":0.2}"#;
+        assert_round_trips::<ItemStatsData>(json);
+        let stats: ItemStatsData = serde_json::from_str(json).unwrap();
+        assert_eq!(stats.percent_attack_speed_mod, Some(0.0));
+        assert_eq!(stats.flat_armor_mod, None);
+    }
+
+    #[test]
+    fn recommended_items_round_trip_without_optional_fields() {
+        assert_round_trips::<ChampionRecommendedData>(
+            r#"{"champion":"Annie","title":"Beginner","map":"SR","mode":"CLASSIC",
+                "type":"riot-beginner","sortrank":1,"customPanel":null,"blocks":[
+                {"type":"starting","items":[{"id":"1056","count":1},{"id":"2003","count":2}]}]}"#,
+        );
+    }
+
+    #[test]
+    fn realm_round_trips() {
+        let realm = r#"{"n":{"item":"10.10.1","rune":"7.23.1","mastery":"7.23.1",
+            "summoner":"10.10.1","champion":"10.10.1","profileicon":"10.10.1","map":"10.10.1",
+            "language":"10.10.1","sticker":"10.10.1"},"v":"10.10.1","l":"en_GB",
+            "cdn":"https://ddragon.leagueoflegends.com/cdn","dd":"10.10.1","lg":"10.10.1",
+            "css":"10.10.1","profileiconmax":28"#;
+        assert_round_trips::<Realm>(&format!("{}}}", realm));
+        assert_round_trips::<Realm>(&format!("{},\"store\":null}}", realm));
+    }
 }
@@ -0,0 +1,29 @@
+//! Serde helper for riot timestamps, which are milliseconds since the unix epoch.
+//!
+//! Use with `#[serde(with = "epoch_millis")]` on `SystemTime` fields.
+use serde::{Deserialize, Deserializer, Serializer};
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub(crate) fn serialize<S: Serializer>(
+    time: &SystemTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let millis = match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => i64::try_from(after.as_millis()).unwrap_or(i64::MAX),
+        Err(before) => -i64::try_from(before.duration().as_millis()).unwrap_or(i64::MAX),
+    };
+    serializer.serialize_i64(millis)
+}
+
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<SystemTime, D::Error> {
+    let millis = i64::deserialize(deserializer)?;
+    let offset = Duration::from_millis(millis.wrapping_abs() as u64);
+    Ok(if millis >= 0 {
+        UNIX_EPOCH + offset
+    } else {
+        UNIX_EPOCH - offset
+    })
+}
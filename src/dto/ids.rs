@@ -0,0 +1,108 @@
+//! Typed identifiers returned and accepted by the riot api.
+//!
+//! Riot uses several opaque string ids per player, which are easy to mix up since every endpoint
+//! only accepts one of them. Each id gets its own type so passing e.g. a [`Puuid`] where an
+//! encrypted [`SummonerId`] is expected fails to compile instead of returning `400 Bad Request`.
+//!
+//! [`Puuid`]: struct.Puuid.html
+//! [`SummonerId`]: struct.SummonerId.html
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Wraps a raw id string
+            #[inline]
+            pub fn new<S: Into<String>>(id: S) -> $name {
+                $name(id.into())
+            }
+
+            #[inline]
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Returns the raw id string
+            #[inline]
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            #[inline]
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<String> for $name {
+            #[inline]
+            fn from(id: String) -> $name {
+                $name(id)
+            }
+        }
+
+        impl From<&str> for $name {
+            #[inline]
+            fn from(id: &str) -> $name {
+                $name(id.to_owned())
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                write!(fmt, "{}({:?})", stringify!($name), self.0)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt.write_str(&self.0)
+            }
+        }
+    };
+}
+
+id_type! {
+    /// Encrypted summoner id, unique per region. Used by mastery and league endpoints.
+    SummonerId
+}
+
+id_type! {
+    /// Encrypted account id, unique per region.
+    AccountId
+}
+
+id_type! {
+    /// Encrypted player uuid, unique across all regions.
+    Puuid
+}
+
+id_type! {
+    /// Match id prefixed with its platform, e.g. `EUW1_5012345678`.
+    MatchId
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MatchId, SummonerId};
+
+    #[test]
+    fn ids_serialize_as_plain_strings() {
+        let id: SummonerId = serde_json::from_str(r#""abc-123""#).unwrap();
+        assert_eq!(id, SummonerId::new("abc-123"));
+        assert_eq!(id.as_str(), "abc-123");
+        assert_eq!(format!("{:?}", id), r#"SummonerId("abc-123")"#);
+        assert_eq!(
+            serde_json::to_string(&MatchId::from("EUW1_1")).unwrap(),
+            r#""EUW1_1""#
+        );
+    }
+}
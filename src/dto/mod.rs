@@ -1,2 +1,15 @@
 pub mod api;
+pub mod cdragon;
 pub mod ddragon;
+pub(crate) mod epoch_millis;
+pub mod ids;
+pub(crate) mod number;
+pub mod static_data;
+
+use std::collections::HashMap;
+
+/// Fields of a response that the DTO does not know about.
+///
+/// Every DTO keeps them in a flattened `extra` map, so serializing a fetched value gives back the
+/// fields Riot added since the DTO was written. A non-empty `extra` is a sign the DTO is outdated.
+pub type ExtraFields = HashMap<String, serde_json::Value>;
@@ -0,0 +1,54 @@
+//! Serde helpers writing whole `f64` values as integers, e.g. `580` instead of `580.0`.
+//!
+//! Ddragon mixes whole and fractional numbers in the same fields, so they are deserialized as
+//! `f64`. Use with `#[serde(serialize_with = "number::serialize")]` to serialize them back the way
+//! they were received.
+use serde::ser::{Serialize, Serializer};
+
+/// Largest magnitude below which every whole `f64` is an exact integer.
+const MAX_EXACT: f64 = 9_007_199_254_740_992.0;
+
+struct Number(f64);
+
+impl Serialize for Number {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.fract() == 0.0 && self.0.abs() < MAX_EXACT {
+            serializer.serialize_i64(self.0 as i64)
+        } else {
+            serializer.serialize_f64(self.0)
+        }
+    }
+}
+
+pub(crate) fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    Number(*value).serialize(serializer)
+}
+
+pub(crate) fn serialize_option<S: Serializer>(
+    value: &Option<f64>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.map(Number).serialize(serializer)
+}
+
+pub(crate) fn serialize_seq<S: Serializer>(
+    values: &[f64],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(values.iter().map(|&value| Number(value)))
+}
+
+/// Serializes spell `effect` lists, whose first entry is usually `null`.
+pub(crate) fn serialize_effects<S: Serializer>(
+    effects: &[Option<Vec<f64>>],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(effects.iter().map(|effect| {
+        effect.as_ref().map(|values| {
+            values
+                .iter()
+                .map(|&value| Number(value))
+                .collect::<Vec<_>>()
+        })
+    }))
+}
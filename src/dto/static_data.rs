@@ -0,0 +1,97 @@
+use crate::constants::{Queue, RankedQueue};
+use crate::dto::ExtraFields;
+use serde::{Deserialize, Serialize};
+
+/// Entry of `queues.json`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueData {
+    pub queue_id: i64,
+    pub map: String,
+    /// Missing for custom games
+    pub description: Option<String>,
+    pub notes: Option<String>,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl QueueData {
+    /// Human readable description, e.g. `5v5 Ranked Solo games on Summoner's Rift`.
+    ///
+    /// Custom games have no description, so only their `map` (`Custom games`) is returned.
+    pub fn describe(&self) -> String {
+        match self.description {
+            Some(ref description) => format!("{} on {}", description, self.map),
+            None => self.map.clone(),
+        }
+    }
+
+    /// Typed queue for this entry's id.
+    pub fn queue(&self) -> Queue {
+        Queue::from_id(self.queue_id)
+    }
+
+    /// Ranked queue this queue belongs to, if any.
+    pub fn ranked_queue(&self) -> Option<RankedQueue> {
+        RankedQueue::from_queue_id(self.queue_id)
+    }
+}
+
+/// Entry of `maps.json`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MapData {
+    pub map_id: i64,
+    pub map_name: String,
+    pub notes: String,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Entry of `gameModes.json`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameModeData {
+    pub game_mode: String,
+    pub description: String,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Entry of `gameTypes.json`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GameTypeData {
+    pub gametype: String,
+    pub description: String,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Entry of `seasons.json`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SeasonData {
+    pub id: i64,
+    pub season: String,
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QueueData;
+
+    #[test]
+    fn describes_queues() {
+        let custom: QueueData = serde_json::from_str(
+            r#"{"queueId": 0, "map": "Custom games", "description": null, "notes": null}"#,
+        )
+        .unwrap();
+        assert_eq!(custom.describe(), "Custom games");
+
+        let aram: QueueData = serde_json::from_str(
+            r#"{"queueId": 450, "map": "Howling Abyss", "description": "5v5 ARAM games", "notes": null}"#,
+        )
+        .unwrap();
+        assert_eq!(aram.describe(), "5v5 ARAM games on Howling Abyss");
+    }
+}
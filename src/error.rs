@@ -1,12 +1,14 @@
 //!
 
-use crate::constants::Region;
-
 use futures::future::{err, ok};
 
 use futures::Future;
+use hyper::header::RETRY_AFTER;
+use hyper::{Method, Response, StatusCode, Uri};
 use snafu::Snafu;
+use std::fmt;
 use std::string::FromUtf8Error;
+use std::time::{Duration, SystemTime};
 
 macro_rules! assert_matches {
     ($expression:expr, $($pattern:tt)+) => {
@@ -18,54 +20,74 @@ macro_rules! assert_matches {
 }
 
 /// Custom error type for an api errors
-// TODO: Remove some of the fields and only keep the ones that the client could catch
-// Example: rate-limiting, service unavailable
+///
+/// Error statuses returned by a server are [`Api`] errors, requests that did not get a response
+/// are [`Transport`] errors. Both carry the failed [`RequestInfo`]; [`is_retryable`] tells whether
+/// sending the request again may help.
+///
+/// [`Api`]: #variant.Api
+/// [`Transport`]: #variant.Transport
+/// [`RequestInfo`]: struct.RequestInfo.html
+/// [`is_retryable`]: #method.is_retryable
 #[derive(Debug, Snafu)]
 #[snafu(visibility(pub(crate)))]
 pub enum ClientError {
-    /// Bad request
-    #[snafu(display("Got 400: Bad Request"))]
-    BadRequest,
-    /// Unauthorized
-    #[snafu(display("Got 401: Unauthorized"))]
-    Unauthorized,
-    /// Forbidden
-    #[snafu(display("Got 403: Forbidden"))]
-    Forbidden,
-    /// Data not found
-    #[snafu(display("Got 404: Data not found"))]
-    DataNotFound,
-    /// Method is not allowwed
-    #[snafu(display("Got 405: Method not allowed"))]
-    MethodNotAllowed,
-    /// Unsupported media type
-    #[snafu(display("Got 415: Unsupported media type"))]
-    UnsupportedMediaType,
-    /// This error is returned when you have exceeded your rate limit for an api.
-    // TODO: Add rate-limiting
-    #[snafu(display("Got 429: Rate limit exceeded. limit: {}", limit))]
-    RateLimitExceeded { limit: usize },
-    /// Internal server error
-    #[snafu(display("Got 500: Internal server error"))]
-    InternalServerError,
-    /// Bad gateway
-    #[snafu(display("Got 502: Bad Gateway"))]
-    BadGateway,
-    /// This error is returned when the riot api servers are on maintenance.
-    /// Please visit https://developer.riotgames.com/api-status/ for more information
-    #[snafu(display("Got 503: Service unavailable for region {:?}", region))]
-    ServiceUnavailable { region: Region },
-    /// Gateway Timeout
-    #[snafu(display("Got 504: Gateway timeout"))]
-    GatewayTimeout,
+    /// The server answered with an error status, e.g. `429 Too Many Requests`.
+    ///
+    /// `retry_after` is the `Retry-After` header of the response, if any, given either in seconds
+    /// or as an HTTP date.
+    #[snafu(display("{} failed with {}", request, status))]
+    Api {
+        request: Box<RequestInfo>,
+        status: StatusCode,
+        retry_after: Option<Duration>,
+    },
+
+    /// The request failed before a response was received, e.g. because the connection dropped.
+    #[snafu(display("{} failed: {}", request, source))]
+    Transport {
+        request: Box<RequestInfo>,
+        source: hyper::Error,
+    },
+
+    /// This error is returned when no ddragon version matches the requested one
+    #[snafu(display("Could not find ddragon version for {}", version))]
+    VersionNotFound { version: String },
+
+    /// This error is returned when no champion matches the given key or name
+    #[snafu(display("Could not find champion {}", query))]
+    ChampionNotFound { query: String },
+
+    /// This error is returned when `queues.json` has no entry for a queue id
+    #[snafu(display("Could not find queue {}", queue_id))]
+    QueueNotFound { queue_id: i64 },
+
+    /// This error is returned when a string does not name any value of a constants type
+    #[snafu(display("Could not parse {} from {:?}", kind, value))]
+    UnknownConstant { kind: &'static str, value: String },
+
+    /// This error is returned when a dragontail directory contains no versioned data
+    #[snafu(display("{} is not a valid dragontail directory", path.display()))]
+    InvalidDragontail { path: std::path::PathBuf },
+
+    /// This error is returned when a response does not match the expected data.
+    /// `path` points at the innermost mismatching value, e.g. `data.Annie.spells[0].maxammo`
+    #[snafu(display(
+        "Could not deserialize {} in response to {}: {}",
+        path,
+        request,
+        source
+    ))]
+    DeserializeError {
+        request: Box<RequestInfo>,
+        path: String,
+        source: serde_json::Error,
+    },
 
     /// Internal url not parsed error
     #[snafu(display("could not parse url"))]
     UrlNotParsed,
 
-    /// Hyper error
-    #[snafu(display("hyper errored: {}", source))]
-    HyperError { source: hyper::Error },
     /// This error is returned when the user provides no token
     #[snafu(display("Please provide the correct RIOT_API_KEY variable because {}", source))]
     NoToken { source: std::env::VarError },
@@ -87,58 +109,198 @@ pub enum ClientError {
 }
 
 impl ClientError {
-    pub fn check_status(region: Region, code: u16) -> Result<(), ClientError> {
-        use self::ClientError::*;
-        match code {
-            400 => Err(BadRequest),
-            401 => Err(Unauthorized),
-            403 => Err(Forbidden),
-            404 => Err(DataNotFound),
-            405 => Err(MethodNotAllowed),
-            415 => Err(UnsupportedMediaType),
-            429 => Err(RateLimitExceeded { limit: 0_usize }),
-            500 => Err(InternalServerError),
-            502 => Err(BadGateway),
-            503 => Err(ServiceUnavailable { region }),
-            504 => Err(GatewayTimeout),
-            _ => Ok(()),
+    /// Http status of an [`Api`](#variant.Api) error.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            ClientError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// How long the server asked to wait before retrying, from the `Retry-After` header.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ClientError::Api { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// The failed request, for errors caused by one.
+    pub fn request(&self) -> Option<&RequestInfo> {
+        match self {
+            ClientError::Api { request, .. }
+            | ClientError::Transport { request, .. }
+            | ClientError::DeserializeError { request, .. } => Some(request),
+            _ => None,
         }
     }
+
+    /// Whether sending the same request again may succeed.
+    ///
+    /// This is the case for rate limits, temporary server errors, timeouts, dropped connections
+    /// and connections failing with an io error, but not for bad requests, missing data or
+    /// configuration errors such as unsupported url schemes.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ClientError::Api { status, .. } => {
+                matches!(status.as_u16(), 429 | 500 | 502 | 503 | 504)
+            }
+            ClientError::Transport { source, .. } => {
+                source.is_closed()
+                    || source.is_incomplete_message()
+                    || source.is_timeout()
+                    || (source.is_connect() && caused_by_io(source))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns an [`Api`](#variant.Api) error if `response` has an error status.
+    pub(crate) fn check_response<B>(
+        request: &RequestInfo,
+        response: &Response<B>,
+    ) -> Result<(), ClientError> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+        Err(ClientError::Api {
+            request: Box::new(request.clone()),
+            status,
+            retry_after,
+        })
+    }
+}
+
+/// Whether an io error is among the causes of `error`.
+fn caused_by_io(error: &(dyn std::error::Error + 'static)) -> bool {
+    let mut cause = error.source();
+    while let Some(error) = cause {
+        if error.is::<std::io::Error>() {
+            return true;
+        }
+        cause = error.source();
+    }
+    false
+}
+
+/// Parses a `Retry-After` value, either a number of seconds or an HTTP date.
+///
+/// Dates in the past yield a zero duration.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or_else(|_| Duration::from_secs(0)),
+    )
+}
+
+/// Method, url and endpoint of a request, attached to the errors it caused.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestInfo {
+    pub method: Method,
+    pub url: Uri,
+    /// Riot api the request belongs to, e.g. `summoner-v4`, or `ddragon` and `cdragon`
+    pub endpoint: String,
+}
+
+impl RequestInfo {
+    pub(crate) fn get(url: Uri, endpoint: &str) -> RequestInfo {
+        RequestInfo {
+            method: Method::GET,
+            url,
+            endpoint: endpoint.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RequestInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} ({})", self.method, self.url, self.endpoint)
+    }
 }
 
 #[cfg(test)]
 mod api_error_tests {
     use super::*;
-    use crate::api::LeagueClient;
-    use crate::constants::Region;
+
+    fn error_for(status: u16, retry_after: Option<&str>) -> ClientError {
+        let request = RequestInfo::get(
+            "https://na1.api.riotgames.com/lol/summoner/v4/summoners/by-name/Vetro"
+                .parse()
+                .unwrap(),
+            "summoner-v4",
+        );
+        let mut response = Response::builder().status(status);
+        if let Some(retry_after) = retry_after {
+            response = response.header(RETRY_AFTER, retry_after);
+        }
+        ClientError::check_response(&request, &response.body(()).unwrap()).unwrap_err()
+    }
 
     #[test]
     fn returns_correct_status_codes() {
-        let lapi = LeagueClient::new(Region::NA).unwrap();
-        let bad_r_err = lapi.get_status(400).unwrap_err();
-        let unauthorized_err = lapi.get_status(401).unwrap_err();
-        let forbidden_err = lapi.get_status(403).unwrap_err();
-        let not_found_err = lapi.get_status(404).unwrap_err();
-        let method_not_allowed_err = lapi.get_status(405).unwrap_err();
-        let unsupported_media_err = lapi.get_status(415).unwrap_err();
-        let rate_err = lapi.get_status(429).unwrap_err();
-        let internal_err = lapi.get_status(500).unwrap_err();
-        let bad_g_err = lapi.get_status(502).unwrap_err();
-        let service_err = lapi.get_status(503).unwrap_err();
-        let gateway_t_err = lapi.get_status(504).unwrap_err();
-        assert_matches!(bad_r_err, ClientError::BadRequest);
-        assert_matches!(unauthorized_err, ClientError::Unauthorized);
-        assert_matches!(forbidden_err, ClientError::Forbidden);
-        assert_matches!(not_found_err, ClientError::DataNotFound);
-        assert_matches!(method_not_allowed_err, ClientError::MethodNotAllowed);
-        assert_matches!(unsupported_media_err, ClientError::UnsupportedMediaType);
-        assert_matches!(rate_err, ClientError::RateLimitExceeded { limit: 0 });
-        assert_matches!(internal_err, ClientError::InternalServerError);
-        assert_matches!(bad_g_err, ClientError::BadGateway);
-        assert_matches!(
-            service_err,
-            ClientError::ServiceUnavailable { region: Region::NA }
+        let rate_err = error_for(429, Some("7"));
+        assert_matches!(rate_err, ClientError::Api { .. });
+        assert_eq!(rate_err.status(), Some(StatusCode::TOO_MANY_REQUESTS));
+        assert_eq!(rate_err.retry_after(), Some(Duration::from_secs(7)));
+        assert_eq!(rate_err.request().unwrap().endpoint, "summoner-v4");
+        assert!(rate_err.is_retryable());
+
+        for &status in &[500, 502, 503, 504] {
+            assert!(error_for(status, None).is_retryable());
+        }
+        for &status in &[400, 401, 403, 404, 405, 415] {
+            let err = error_for(status, None);
+            assert_eq!(err.status().map(|s| s.as_u16()), Some(status));
+            assert_eq!(err.retry_after(), None);
+            assert!(!err.is_retryable());
+        }
+        assert_eq!(
+            error_for(404, None).to_string(),
+            "GET https://na1.api.riotgames.com/lol/summoner/v4/summoners/by-name/Vetro \
+             (summoner-v4) failed with 404 Not Found"
         );
-        assert_matches!(gateway_t_err, ClientError::GatewayTimeout)
+    }
+
+    #[test]
+    fn parses_retry_after_dates() {
+        let in_a_minute = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(60));
+        let retry_after = error_for(503, Some(&in_a_minute)).retry_after().unwrap();
+        assert!(retry_after > Duration::from_secs(55) && retry_after <= Duration::from_secs(60));
+
+        let past = error_for(429, Some("Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert_eq!(past.retry_after(), Some(Duration::from_secs(0)));
+        assert_eq!(error_for(429, Some("soon")).retry_after(), None);
+    }
+
+    async fn transport_error(url: &str) -> ClientError {
+        let url: Uri = url.parse().unwrap();
+        let source = crate::utils::construct_hyper_client()
+            .get(url.clone())
+            .await
+            .unwrap_err();
+        ClientError::Transport {
+            request: Box::new(RequestInfo::get(url, "test")),
+            source,
+        }
+    }
+
+    #[test]
+    fn retries_only_io_transport_errors() {
+        smol::run(async {
+            assert!(!transport_error("ftp://localhost/").await.is_retryable());
+            // Nothing listens on port 1, so the connection is refused
+            assert!(transport_error("http://127.0.0.1:1/").await.is_retryable());
+        })
     }
 }
@@ -5,8 +5,10 @@
 //! Narwhalol bundles both Riot League of Legends and DDragon wrapper clients in itself.
 extern crate hyper;
 pub mod api;
+pub mod cdragon;
 #[cfg_attr(tarpaulin, skip)]
 pub mod constants;
+pub(crate) mod de;
 pub mod ddragon;
 #[cfg_attr(tarpaulin, skip)]
 #[allow(missing_docs)]
@@ -18,9 +20,16 @@ pub(crate) mod utils;
 
 pub use {
     api::LeagueClient,
-    constants::{LanguageCode, RankedQueue, Region},
+    cdragon::CDragonClient,
+    constants::{
+        Champion, ChampionTag, LanguageCode, MaybeKnown, Rank, RankedQueue, Region, RegionalRoute,
+        ResourceType,
+    },
     dto::api::*,
+    dto::cdragon::*,
     dto::ddragon::*,
+    dto::ids::*,
+    dto::static_data::*,
 };
 
 #[cfg(test)]
@@ -28,6 +37,12 @@ mod tests {
     use crate::{Summoner, LeagueClient, Region};
     use std::time::Duration;
 
+    #[cfg(any(feature = "tokio_rt", feature = "smol_rt"))]
+    async fn get_summoner_vetro() -> Summoner {
+        let lapi = LeagueClient::new(Region::RU).unwrap();
+        lapi.get_summoner_by_name("Vetro").await.unwrap()
+    }
+
     #[test]
     #[cfg(feature = "async_std_rt")]
     fn ensure_different_runtimes_work_with_lib() {
@@ -1,4 +1,7 @@
-use crate::error::{ClientError, HyperError};
+use crate::constants::Region;
+use crate::de;
+use crate::dto::ddragon::Realm;
+use crate::error::{ClientError, RequestInfo, Transport};
 use crate::types::{Cache, Client};
 use futures::prelude::*;
 use hyper::header::HeaderValue;
@@ -13,7 +16,11 @@ use async_trait::async_trait;
 use crate::error::*;
 use snafu::ResultExt;
 use std::fmt::Debug;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 #[async_trait]
 pub(crate) trait CachedClient {
@@ -23,29 +30,168 @@ pub(crate) trait CachedClient {
     ) -> Result<T, ClientError>;
 }
 
-pub(crate) async fn get_latest_ddragon_version(client: Client) -> Result<String, ClientError> {
-    let resp = client
-        .get(
-            "https://ddragon.leagueoflegends.com/api/versions.json"
-                .parse()
-                .unwrap(),
-        )
-        .await
-        .context(HyperError)?;
-    let body = resp
-        .into_body()
-        .try_fold(Vec::new(), |mut body, chunk| async move {
-            body.extend_from_slice(&chunk);
-            Ok(body)
-        })
+/// Sends `req`, returning the body of a successful response.
+///
+/// Error statuses become [`ClientError::Api`] errors and failed requests [`ClientError::Transport`]
+/// errors, both carrying `request`.
+pub(crate) async fn fetch(
+    client: &Client,
+    req: Request<Body>,
+    request: &RequestInfo,
+) -> Result<String, ClientError> {
+    let resp = client.request(req).await.context(Transport {
+        request: Box::new(request.clone()),
+    })?;
+    ClientError::check_response(request, &resp)?;
+    let bytes = hyper::body::to_bytes(resp.into_body())
         .await
-        .context(HyperError)?;
-    let string_resp = String::from_utf8(body).context(FromUTF8Error)?;
-    let mut versions: Vec<String> = serde_json::from_str(&string_resp).unwrap();
+        .context(Transport {
+            request: Box::new(request.clone()),
+        })?;
+    String::from_utf8(bytes.to_vec()).context(FromUTF8Error)
+}
+
+/// Fetches and deserializes ddragon json from `url` bypassing any cache.
+pub(crate) async fn get_json<T: DeserializeOwned>(
+    client: Client,
+    url: Uri,
+) -> Result<T, ClientError> {
+    let request = RequestInfo::get(url.clone(), "ddragon");
+    let req = Request::get(url).body(Body::empty()).unwrap();
+    let mut body = fetch(&client, req, &request).await?;
+    de::from_response(&mut body, &request, false)
+}
+
+pub(crate) const DDRAGON_VERSIONS_URL: &str =
+    "https://ddragon.leagueoflegends.com/api/versions.json";
+
+pub(crate) async fn get_ddragon_versions(client: Client) -> Result<Vec<String>, ClientError> {
+    get_json(client, DDRAGON_VERSIONS_URL.parse().unwrap()).await
+}
+
+pub(crate) fn ddragon_realm_url(region: &Region) -> Uri {
+    format!(
+        "https://ddragon.leagueoflegends.com/realms/{}.json",
+        region.as_str().to_lowercase()
+    )
+    .parse()
+    .unwrap()
+}
+
+pub(crate) async fn get_ddragon_realm(
+    client: Client,
+    region: &Region,
+) -> Result<Realm, ClientError> {
+    get_json(client, ddragon_realm_url(region)).await
+}
+
+pub(crate) async fn get_latest_ddragon_version(client: Client) -> Result<String, ClientError> {
+    let mut versions = get_ddragon_versions(client).await?;
     let version = versions.remove(0);
     Ok(version)
 }
 
+/// Guesses the ddragon version of the patch a match `gameVersion` string (e.g. `13.5.497.1234`)
+/// belongs to (e.g. `13.5.1`).
+///
+/// Not every patch has a `.1` build, so the result is only the `major.minor.` prefix to look up in
+/// the version list. Returns `None` if the string does not start with `major.minor`.
+pub(crate) fn ddragon_version_from_game_version(game_version: &str) -> Option<String> {
+    let mut parts = game_version.split('.');
+    let major: u32 = parts.next()?.parse().ok()?;
+    let minor: u32 = parts.next()?.parse().ok()?;
+    Some(format!("{}.{}.1", major, minor))
+}
+
+/// Unpacks a `dragontail-<version>.tgz` archive next to itself and returns the extracted directory.
+///
+/// Every call extracts into its own temporary sibling directory that is renamed into place once
+/// complete, so neither an interrupted unpack nor a concurrent one is mistaken for a finished one.
+/// If the directory already exists, the archive is assumed to be unpacked already.
+pub(crate) fn unpack_dragontail(archive: &Path) -> Result<PathBuf, ClientError> {
+    static UNPACKS: AtomicUsize = AtomicUsize::new(0);
+
+    let file_name = archive
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let dir_name = file_name
+        .trim_end_matches(".tgz")
+        .trim_end_matches(".tar.gz")
+        .to_owned();
+    let target = archive.with_file_name(&dir_name);
+    if !target.is_dir() {
+        let partial = archive.with_file_name(format!(
+            ".{}.{}-{}.partial",
+            dir_name,
+            std::process::id(),
+            UNPACKS.fetch_add(1, Ordering::Relaxed)
+        ));
+        debug!("Unpacking {:?} into {:?}", archive, &partial);
+        let unpacked = File::open(archive).and_then(|file| {
+            tar::Archive::new(flate2::read::GzDecoder::new(file)).unpack(&partial)
+        });
+        if let Err(err) = unpacked {
+            let _ = fs::remove_dir_all(&partial);
+            return Err(err).context(IOError);
+        }
+        if let Err(err) = fs::rename(&partial, &target) {
+            // Another unpack of the same archive finished first
+            fs::remove_dir_all(&partial).context(IOError)?;
+            if !target.is_dir() {
+                return Err(err).context(IOError);
+            }
+        }
+    }
+    Ok(target)
+}
+
+/// Finds the newest versioned data directory (e.g. `10.10.1`) inside an extracted dragontail.
+pub(crate) fn find_dragontail_version(root: &Path) -> Option<String> {
+    fs::read_dir(root)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().join("data").is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter_map(|name| {
+            let parsed = name
+                .split('.')
+                .map(|part| part.parse::<u32>())
+                .collect::<Result<Vec<_>, _>>()
+                .ok()?;
+            Some((parsed, name))
+        })
+        .max()
+        .map(|(_, name)| name)
+}
+
+/// Waits for `duration` using the timer of the enabled runtime
+pub(crate) async fn delay(duration: Duration) {
+    #[cfg(feature = "smol_rt")]
+    smol::Timer::after(duration).await;
+    #[cfg(feature = "async_std_rt")]
+    async_std::task::sleep(duration).await;
+    #[cfg(feature = "tokio_rt")]
+    tokio::time::delay_for(duration).await;
+}
+
+/// Runs blocking `f` on the blocking thread pool of the enabled runtime
+pub(crate) async fn unblock<T, F>(f: F) -> T
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    #[cfg(feature = "smol_rt")]
+    let result = smol::Task::blocking(async move { f() }).await;
+    #[cfg(feature = "async_std_rt")]
+    let result = async_std::task::spawn_blocking(f).await;
+    #[cfg(feature = "tokio_rt")]
+    let result = tokio::task::spawn_blocking(f)
+        .await
+        .expect("blocking task panicked");
+    result
+}
+
 /// Helper function that constructs an https hyper client
 pub(crate) fn construct_hyper_client() -> Client {
     let mut builder = HttpClient::builder();
@@ -59,3 +205,21 @@ pub(crate) fn construct_hyper_client() -> Client {
         .build::<_, Body>(compat::CompatConnector::new());
     Arc::new(cli)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ddragon_version_from_game_version;
+
+    #[test]
+    fn converts_game_version_to_ddragon_version() {
+        assert_eq!(
+            ddragon_version_from_game_version("13.5.497.1234"),
+            Some("13.5.1".to_owned())
+        );
+        assert_eq!(
+            ddragon_version_from_game_version("10.10"),
+            Some("10.10.1".to_owned())
+        );
+        assert_eq!(ddragon_version_from_game_version("lolpatch_3.7"), None);
+    }
+}